@@ -0,0 +1,758 @@
+use crate::assembly::{sentence_numbers, SequenceTracker};
+use crate::errors::{AisDecodeError, AssemblyError};
+use crate::parse::{
+    AisChannel, AisNavigationStatus, GpsPosition, LatitudeDirection, LongitudeDirection, VdmData,
+};
+use crate::parsers::sign_extend;
+use core::fmt;
+
+/// The maximum amount of bits an AisPayload can hold, this equals the
+/// longest message that fits into the five slots a transmission can occupy
+pub const MAX_AIS_PAYLOAD_BITS: usize = 1008;
+
+/// The maximum amount of multi sentence messages an AisAssembler can
+/// assemble at the same time
+pub const MAX_AIS_MESSAGES_IN_PROGRESS: usize = 4;
+
+/// The maximum length of a text field inside of an AIS message
+pub const MAX_AIS_TEXT_LENGTH: usize = 20;
+
+/// Undoes the armoring of a single payload character into its six bits
+fn dearmor(character: u8) -> Result<u8, AisDecodeError> {
+    match character {
+        b'0'..=b'W' => Ok(character - b'0'),
+        b'`'..=b'w' => Ok(character - b'0' - 8),
+        _ => Err(AisDecodeError::ArmoringError),
+    }
+}
+
+/// The binary payload of an AIS message with its armoring undone
+#[derive(Clone, Copy, PartialEq)]
+pub struct AisPayload {
+    bits: [u8; MAX_AIS_PAYLOAD_BITS / 8],
+    length: usize,
+}
+
+impl AisPayload {
+    fn new() -> Self {
+        AisPayload {
+            bits: [0; MAX_AIS_PAYLOAD_BITS / 8],
+            length: 0,
+        }
+    }
+
+    /// Decodes the payload of a VDM or VDO sentence that carries a whole message,
+    /// messages split across several sentences have to go through an AisAssembler
+    pub fn from_sentence(data: &VdmData) -> Result<Self, AisDecodeError> {
+        let mut payload = Self::new();
+        payload.push(data.payload, data.fill_bits.unwrap_or(0))?;
+        Ok(payload)
+    }
+
+    /// Returns the amount of bits in the payload
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns true if the payload does not contain any bits
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the payload packed into bytes with the most significant bit first,
+    /// the bits of the last byte that exceed the length are zero
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bits[..self.length.div_ceil(8)]
+    }
+
+    /// Returns the type of the message, it is stored in the first six bits
+    pub fn message_type(&self) -> Option<u8> {
+        if self.length < 6 {
+            return None;
+        }
+
+        Some(self.unsigned(0, 6) as u8)
+    }
+
+    /// Decodes the payload into a typed message
+    pub fn decode(&self) -> Result<AisMessage, AisDecodeError> {
+        let message_type = self
+            .message_type()
+            .ok_or(AisDecodeError::LengthError(self.length))?;
+
+        match message_type {
+            1..=3 => {
+                self.check_length(168)?;
+                Ok(AisMessage::PositionReport(self.position_report()))
+            }
+            5 => {
+                self.check_length(420)?;
+                Ok(AisMessage::StaticAndVoyageData(
+                    self.static_and_voyage_data(),
+                ))
+            }
+            18 => {
+                self.check_length(168)?;
+                Ok(AisMessage::ClassBPositionReport(
+                    self.class_b_position_report(),
+                ))
+            }
+            19 => {
+                self.check_length(312)?;
+                Ok(AisMessage::ExtendedClassBPositionReport(
+                    self.extended_class_b_position_report(),
+                ))
+            }
+            21 => {
+                self.check_length(272)?;
+                Ok(AisMessage::AidToNavigationReport(
+                    self.aid_to_navigation_report(),
+                ))
+            }
+            24 => {
+                self.check_length(160)?;
+                Ok(AisMessage::StaticDataReport(self.static_data_report()?))
+            }
+            message_type => Err(AisDecodeError::TypeNotImplementedError(message_type)),
+        }
+    }
+
+    /// Appends the armored characters of a sentence, the fill bits are
+    /// dropped from the end of the last character
+    fn push(&mut self, armored: &[u8], fill_bits: u8) -> Result<(), AisDecodeError> {
+        let fill_bits = usize::from(fill_bits);
+        let bits = armored.len() * 6;
+        if fill_bits > 5 || fill_bits > bits {
+            return Err(AisDecodeError::ArmoringError);
+        }
+        if self.length + bits - fill_bits > MAX_AIS_PAYLOAD_BITS {
+            return Err(AisDecodeError::CapacityError);
+        }
+
+        for (index, character) in armored.iter().enumerate() {
+            let value = dearmor(*character)?;
+            for bit in 0..6 {
+                let offset = index * 6 + bit;
+                if offset >= bits - fill_bits {
+                    break;
+                }
+
+                let position = self.length + offset;
+                if (value >> (5 - bit)) & 1 == 1 {
+                    self.bits[position / 8] |= 0x80 >> (position % 8);
+                }
+            }
+        }
+
+        self.length += bits - fill_bits;
+        Ok(())
+    }
+
+    fn check_length(&self, minimum: usize) -> Result<(), AisDecodeError> {
+        if self.length < minimum {
+            return Err(AisDecodeError::LengthError(self.length));
+        }
+
+        Ok(())
+    }
+
+    /// Reads an unsigned field, bits beyond the end of the payload are read as zero
+    fn unsigned(&self, start: usize, bits: usize) -> u32 {
+        let mut value = 0;
+        for position in start..start + bits {
+            let bit = if position < self.length {
+                (self.bits[position / 8] >> (7 - position % 8)) & 1
+            } else {
+                0
+            };
+            value = (value << 1) | u32::from(bit);
+        }
+
+        value
+    }
+
+    fn signed(&self, start: usize, bits: usize) -> i32 {
+        sign_extend(self.unsigned(start, bits), bits as u32)
+    }
+
+    fn flag(&self, position: usize) -> bool {
+        self.unsigned(position, 1) == 1
+    }
+
+    /// Reads a field that uses the given value to mark it as not available
+    fn optional(&self, start: usize, bits: usize, not_available: u32) -> Option<u32> {
+        match self.unsigned(start, bits) {
+            value if value == not_available => None,
+            value => Some(value),
+        }
+    }
+
+    /// Reads a field of six bit characters, the text ends at the first `@`
+    /// and trailing spaces are padding
+    fn text(&self, start: usize, characters: usize) -> AisText {
+        let mut text = AisText {
+            bytes: [0; MAX_AIS_TEXT_LENGTH],
+            length: 0,
+        };
+
+        for index in 0..characters.min(MAX_AIS_TEXT_LENGTH) {
+            let value = self.unsigned(start + index * 6, 6) as u8;
+            if value == 0 {
+                break;
+            }
+
+            text.bytes[index] = if value < 32 { value + 64 } else { value };
+            text.length += 1;
+        }
+        while text.length > 0 && text.bytes[text.length - 1] == b' ' {
+            text.length -= 1;
+        }
+
+        text
+    }
+
+    /// Reads a longitude and latitude in 1/10000 minutes, 181 and 91 degrees
+    /// mark them as not available
+    fn position(&self, start: usize) -> Option<GpsPosition> {
+        let lon = self.signed(start, 28);
+        let lat = self.signed(start + 28, 27);
+        if lon.unsigned_abs() > 180 * 600_000 || lat.unsigned_abs() > 90 * 600_000 {
+            return None;
+        }
+
+        Some(GpsPosition {
+            lat: lat.unsigned_abs() as f32 / 600_000.,
+            lat_dir: if lat < 0 {
+                LatitudeDirection::South
+            } else {
+                LatitudeDirection::North
+            },
+            lon: lon.unsigned_abs() as f32 / 600_000.,
+            lon_dir: if lon < 0 {
+                LongitudeDirection::West
+            } else {
+                LongitudeDirection::East
+            },
+        })
+    }
+
+    fn dimensions(&self, start: usize) -> Option<AisDimensions> {
+        let dimensions = AisDimensions {
+            to_bow: self.unsigned(start, 9) as u16,
+            to_stern: self.unsigned(start + 9, 9) as u16,
+            to_port: self.unsigned(start + 18, 6) as u8,
+            to_starboard: self.unsigned(start + 24, 6) as u8,
+        };
+
+        if dimensions.length() == 0 && dimensions.beam() == 0 {
+            None
+        } else {
+            Some(dimensions)
+        }
+    }
+
+    fn tenths(&self, start: usize, bits: usize, not_available: u32) -> Option<f32> {
+        self.optional(start, bits, not_available)
+            .map(|value| value as f32 / 10.)
+    }
+
+    fn timestamp(&self, start: usize) -> Option<u8> {
+        match self.unsigned(start, 6) {
+            second if second < 60 => Some(second as u8),
+            _ => None,
+        }
+    }
+
+    fn position_report(&self) -> AisPositionReport {
+        AisPositionReport {
+            message_type: self.unsigned(0, 6) as u8,
+            repeat: self.unsigned(6, 2) as u8,
+            mmsi: self.unsigned(8, 30),
+            navigation_status: AisNavigationStatus::try_from(self.unsigned(38, 4) as u8).ok(),
+            rate_of_turn: match self.signed(42, 8) {
+                -128 => None,
+                rate_of_turn => Some(rate_of_turn as i8),
+            },
+            speed_over_ground: self.tenths(50, 10, 1023),
+            position_accuracy: self.flag(60),
+            position: self.position(61),
+            course_over_ground: self.tenths(116, 12, 3600),
+            true_heading: self.optional(128, 9, 511).map(|heading| heading as u16),
+            timestamp: self.timestamp(137),
+            raim: self.flag(148),
+        }
+    }
+
+    fn class_b_position_report(&self) -> AisPositionReport {
+        AisPositionReport {
+            message_type: self.unsigned(0, 6) as u8,
+            repeat: self.unsigned(6, 2) as u8,
+            mmsi: self.unsigned(8, 30),
+            navigation_status: None,
+            rate_of_turn: None,
+            speed_over_ground: self.tenths(46, 10, 1023),
+            position_accuracy: self.flag(56),
+            position: self.position(57),
+            course_over_ground: self.tenths(112, 12, 3600),
+            true_heading: self.optional(124, 9, 511).map(|heading| heading as u16),
+            timestamp: self.timestamp(133),
+            raim: self.flag(147),
+        }
+    }
+
+    fn extended_class_b_position_report(&self) -> AisExtendedClassBReport {
+        let mut report = self.class_b_position_report();
+        report.raim = self.flag(305);
+
+        AisExtendedClassBReport {
+            report,
+            vessel_name: self.text(143, 20),
+            ship_type: self.optional(263, 8, 0).map(|ship_type| ship_type as u8),
+            dimensions: self.dimensions(271),
+            epfd: self.optional(301, 4, 0).map(|epfd| epfd as u8),
+            dte_ready: !self.flag(306),
+        }
+    }
+
+    fn static_and_voyage_data(&self) -> AisStaticAndVoyageData {
+        AisStaticAndVoyageData {
+            repeat: self.unsigned(6, 2) as u8,
+            mmsi: self.unsigned(8, 30),
+            ais_version: self.unsigned(38, 2) as u8,
+            imo_number: self.optional(40, 30, 0),
+            call_sign: self.text(70, 7),
+            vessel_name: self.text(112, 20),
+            ship_type: self.optional(232, 8, 0).map(|ship_type| ship_type as u8),
+            dimensions: self.dimensions(240),
+            epfd: self.optional(270, 4, 0).map(|epfd| epfd as u8),
+            eta: AisEta {
+                month: self.optional(274, 4, 0).map(|month| month as u8),
+                day: self.optional(278, 5, 0).map(|day| day as u8),
+                hour: self.optional(283, 5, 24).map(|hour| hour as u8),
+                minute: self.optional(288, 6, 60).map(|minute| minute as u8),
+            },
+            draught: self.tenths(294, 8, 0),
+            destination: self.text(302, 20),
+            dte_ready: !self.flag(422),
+        }
+    }
+
+    fn aid_to_navigation_report(&self) -> AisAidToNavigationReport {
+        // The name extension fills up the rest of the payload in whole characters
+        let extension_characters = (self.length.saturating_sub(272) / 6).min(14);
+
+        AisAidToNavigationReport {
+            repeat: self.unsigned(6, 2) as u8,
+            mmsi: self.unsigned(8, 30),
+            aid_type: self.optional(38, 5, 0).map(|aid_type| aid_type as u8),
+            name: self.text(43, 20),
+            name_extension: self.text(272, extension_characters),
+            position_accuracy: self.flag(163),
+            position: self.position(164),
+            dimensions: self.dimensions(219),
+            epfd: self.optional(249, 4, 0).map(|epfd| epfd as u8),
+            timestamp: self.timestamp(253),
+            off_position: self.flag(259),
+            raim: self.flag(268),
+            virtual_aid: self.flag(269),
+        }
+    }
+
+    fn static_data_report(&self) -> Result<AisStaticDataReport, AisDecodeError> {
+        let part = match self.unsigned(38, 2) {
+            0 => AisStaticDataPart::A {
+                vessel_name: self.text(40, 20),
+            },
+            1 => {
+                self.check_length(168)?;
+                AisStaticDataPart::B {
+                    ship_type: self.optional(40, 8, 0).map(|ship_type| ship_type as u8),
+                    vendor_id: self.text(48, 3),
+                    call_sign: self.text(90, 7),
+                    dimensions: self.dimensions(132),
+                }
+            }
+            _ => return Err(AisDecodeError::FieldError),
+        };
+
+        Ok(AisStaticDataReport {
+            repeat: self.unsigned(6, 2) as u8,
+            mmsi: self.unsigned(8, 30),
+            part,
+        })
+    }
+}
+
+impl fmt::Debug for AisPayload {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AisPayload")
+            .field("length", &self.length)
+            .field("bits", &self.as_bytes())
+            .finish()
+    }
+}
+
+/// A text field of an AIS message such as a vessel name
+#[derive(Clone, Copy, PartialEq)]
+pub struct AisText {
+    bytes: [u8; MAX_AIS_TEXT_LENGTH],
+    length: usize,
+}
+
+impl AisText {
+    /// Returns the text without its padding
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[..self.length]).unwrap_or("")
+    }
+
+    /// Returns true if the field was left empty
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+}
+
+impl fmt::Debug for AisText {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// The dimensions of a vessel or aid to navigation in meters,
+/// measured from the reference point of its reported position
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AisDimensions {
+    pub to_bow: u16,
+    pub to_stern: u16,
+    pub to_port: u8,
+    pub to_starboard: u8,
+}
+
+impl AisDimensions {
+    /// Returns the overall length
+    pub fn length(&self) -> u16 {
+        self.to_bow + self.to_stern
+    }
+
+    /// Returns the overall beam
+    pub fn beam(&self) -> u16 {
+        u16::from(self.to_port) + u16::from(self.to_starboard)
+    }
+}
+
+/// The estimated time of arrival in UTC, the year is not transmitted
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AisEta {
+    pub month: Option<u8>,
+    pub day: Option<u8>,
+    pub hour: Option<u8>,
+    pub minute: Option<u8>,
+}
+
+/// A position report of message type 1, 2, 3 or 18. Class B reports
+/// neither contain a navigation status nor a rate of turn.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AisPositionReport {
+    pub message_type: u8,
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub navigation_status: Option<AisNavigationStatus>,
+    /// The rate of turn as sent, this is 4.733 times the square root of the
+    /// rate in degrees per minute, negative values mean turning to port.
+    /// 127 and -127 mean turning faster than 5 degrees per 30 seconds.
+    pub rate_of_turn: Option<i8>,
+    /// The speed over ground in knots
+    pub speed_over_ground: Option<f32>,
+    /// True if the position is accurate to less than 10 meters
+    pub position_accuracy: bool,
+    pub position: Option<GpsPosition>,
+    /// The course over ground in degrees
+    pub course_over_ground: Option<f32>,
+    /// The true heading in degrees
+    pub true_heading: Option<u16>,
+    /// The second of the UTC minute the report was generated in
+    pub timestamp: Option<u8>,
+    pub raim: bool,
+}
+
+/// An extended class B position report of message type 19
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AisExtendedClassBReport {
+    pub report: AisPositionReport,
+    pub vessel_name: AisText,
+    pub ship_type: Option<u8>,
+    pub dimensions: Option<AisDimensions>,
+    /// The type of the electronic position fixing device
+    pub epfd: Option<u8>,
+    pub dte_ready: bool,
+}
+
+/// The static and voyage related data of message type 5
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AisStaticAndVoyageData {
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub ais_version: u8,
+    pub imo_number: Option<u32>,
+    pub call_sign: AisText,
+    pub vessel_name: AisText,
+    pub ship_type: Option<u8>,
+    pub dimensions: Option<AisDimensions>,
+    /// The type of the electronic position fixing device
+    pub epfd: Option<u8>,
+    pub eta: AisEta,
+    /// The maximum present static draught in meters
+    pub draught: Option<f32>,
+    pub destination: AisText,
+    pub dte_ready: bool,
+}
+
+/// An aid to navigation report of message type 21
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AisAidToNavigationReport {
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub aid_type: Option<u8>,
+    pub name: AisText,
+    /// Continues the name if it is longer than 20 characters
+    pub name_extension: AisText,
+    /// True if the position is accurate to less than 10 meters
+    pub position_accuracy: bool,
+    pub position: Option<GpsPosition>,
+    pub dimensions: Option<AisDimensions>,
+    /// The type of the electronic position fixing device
+    pub epfd: Option<u8>,
+    /// The second of the UTC minute the report was generated in
+    pub timestamp: Option<u8>,
+    /// True if a floating aid has drifted off its assigned position
+    pub off_position: bool,
+    pub raim: bool,
+    /// True if the aid does not physically exist
+    pub virtual_aid: bool,
+}
+
+/// The part of a static data report, class B transponders send
+/// their static data in two separate messages
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AisStaticDataPart {
+    A {
+        vessel_name: AisText,
+    },
+    B {
+        ship_type: Option<u8>,
+        vendor_id: AisText,
+        call_sign: AisText,
+        dimensions: Option<AisDimensions>,
+    },
+}
+
+/// A static data report of message type 24
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AisStaticDataReport {
+    pub repeat: u8,
+    pub mmsi: u32,
+    pub part: AisStaticDataPart,
+}
+
+/// An AIS message decoded out of its payload
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AisMessage {
+    /// Message types 1, 2 and 3
+    PositionReport(AisPositionReport),
+    /// Message type 5
+    StaticAndVoyageData(AisStaticAndVoyageData),
+    /// Message type 18
+    ClassBPositionReport(AisPositionReport),
+    /// Message type 19
+    ExtendedClassBPositionReport(AisExtendedClassBReport),
+    /// Message type 21
+    AidToNavigationReport(AisAidToNavigationReport),
+    /// Message type 24
+    StaticDataReport(AisStaticDataReport),
+}
+
+/// Collects the fragments of AIS messages until they are complete and turns
+/// them into an AisPayload. Messages are told apart by their sequential message
+/// id and channel, VDM and VDO sentences should be fed into separate assemblers.
+/// Works on fixed capacity storage so it does not require any allocations.
+#[derive(Debug, Clone)]
+pub struct AisAssembler {
+    messages:
+        SequenceTracker<(Option<u8>, Option<AisChannel>), AisPayload, MAX_AIS_MESSAGES_IN_PROGRESS>,
+}
+
+impl Default for AisAssembler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AisAssembler {
+    /// Generates a new assembler without any messages in progress
+    pub fn new() -> Self {
+        AisAssembler {
+            messages: SequenceTracker::new(),
+        }
+    }
+
+    /// Discards all messages that are in progress
+    pub fn reset(&mut self) {
+        self.messages.reset();
+    }
+
+    /// Adds a VDM or VDO fragment to its message, returns the AisPayload once
+    /// the last fragment of it has been added. Single sentence messages are
+    /// returned right away.
+    ///
+    /// If a fragment does not continue the message in progress the message is
+    /// discarded and a SentenceOrderError is returned. A first fragment always
+    /// starts a new message though, so it is kept even if it caused the error.
+    pub fn push(&mut self, data: &VdmData) -> Result<Option<AisPayload>, AssemblyError> {
+        let (fragment_count, fragment_number) =
+            sentence_numbers(data.fragment_count, data.fragment_number)?;
+        if fragment_count == 1 {
+            return AisPayload::from_sentence(data)
+                .map(Some)
+                .map_err(assembly_error);
+        }
+
+        // Only the last fragment may end on a partial character
+        let fill_bits = if fragment_number == fragment_count {
+            data.fill_bits.unwrap_or(0)
+        } else {
+            0
+        };
+        self.messages.push(
+            (data.message_id, data.channel),
+            fragment_count,
+            fragment_number,
+            AisPayload::new,
+            |payload| {
+                payload
+                    .push(data.payload, fill_bits)
+                    .map_err(assembly_error)
+            },
+        )
+    }
+}
+
+fn assembly_error(error: AisDecodeError) -> AssemblyError {
+    match error {
+        AisDecodeError::ArmoringError => AssemblyError::InvalidDataError,
+        _ => AssemblyError::CapacityError,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::vdm::parse_vdm;
+
+    const FIRST: &[u8] = b"2,1,3,B,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*";
+    const SECOND: &[u8] = b"2,2,3,B,1@0000000000000,2*";
+
+    fn vdm(data: &'static [u8]) -> VdmData<'static> {
+        parse_vdm(data).unwrap().1
+    }
+
+    fn assert_static_data(payload: AisPayload) {
+        assert_eq!(payload.len(), 424);
+        match payload.decode() {
+            Ok(AisMessage::StaticAndVoyageData(data)) => {
+                assert_eq!(data.mmsi, 369190000);
+                assert_eq!(data.vessel_name.as_str(), "MT.MITCHELL");
+                assert_eq!(data.destination.as_str(), "SEATTLE");
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    #[test]
+    fn assembles_a_multi_fragment_message() {
+        let mut assembler = AisAssembler::new();
+        assert_eq!(assembler.push(&vdm(FIRST)), Ok(None));
+        assert_static_data(assembler.push(&vdm(SECOND)).unwrap().unwrap());
+    }
+
+    #[test]
+    fn returns_single_fragment_messages_right_away() {
+        let mut assembler = AisAssembler::new();
+        let payload = assembler
+            .push(&vdm(b"1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(payload.len(), 168);
+        assert_eq!(payload.message_type(), Some(1));
+    }
+
+    #[test]
+    fn rejects_fragments_of_another_message_id() {
+        let mut assembler = AisAssembler::new();
+        assert_eq!(assembler.push(&vdm(FIRST)), Ok(None));
+        assert_eq!(
+            assembler.push(&vdm(b"2,2,4,B,1@0000000000000,2*")),
+            Err(AssemblyError::SentenceOrderError {
+                expected: 1,
+                received: 2
+            })
+        );
+
+        // The message with the matching id is still in progress
+        assert_static_data(assembler.push(&vdm(SECOND)).unwrap().unwrap());
+    }
+
+    #[test]
+    fn assembles_interleaved_channels() {
+        let mut assembler = AisAssembler::new();
+        assert_eq!(assembler.push(&vdm(FIRST)), Ok(None));
+        assert_eq!(
+            assembler.push(&vdm(
+                b"2,1,3,A,55P5TL01VIaAL@7WKO@mBplU@<PDhh000000001S;AJ::4A80?4i@E53,0*"
+            )),
+            Ok(None)
+        );
+        assert_static_data(
+            assembler
+                .push(&vdm(b"2,2,3,A,1@0000000000000,2*"))
+                .unwrap()
+                .unwrap(),
+        );
+        assert_static_data(assembler.push(&vdm(SECOND)).unwrap().unwrap());
+    }
+
+    #[test]
+    fn reports_payloads_beyond_the_capacity() {
+        // Three fragments of 360 bits each exceed the longest possible payload
+        let mut assembler = AisAssembler::new();
+        assert_eq!(
+            assembler.push(&vdm(
+                b"3,1,1,A,000000000000000000000000000000000000000000000000000000000000,0*"
+            )),
+            Ok(None)
+        );
+        assert_eq!(
+            assembler.push(&vdm(
+                b"3,2,1,A,000000000000000000000000000000000000000000000000000000000000,0*"
+            )),
+            Ok(None)
+        );
+        assert_eq!(
+            assembler.push(&vdm(
+                b"3,3,1,A,000000000000000000000000000000000000000000000000000000000000,0*"
+            )),
+            Err(AssemblyError::CapacityError)
+        );
+    }
+
+    #[test]
+    fn rejects_characters_outside_of_the_armoring() {
+        let mut assembler = AisAssembler::new();
+        assert_eq!(
+            assembler.push(&vdm(b"2,1,1,A,55P5X,0*")),
+            Err(AssemblyError::InvalidDataError)
+        );
+        assert_eq!(
+            assembler.push(&vdm(b"1,1,,A,55P5X,0*")),
+            Err(AssemblyError::InvalidDataError)
+        );
+    }
+}
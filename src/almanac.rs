@@ -0,0 +1,168 @@
+use crate::assembly::{sentence_numbers, SequenceTracker};
+use crate::errors::AssemblyError;
+use crate::parse::AlmData;
+
+/// The maximum amount of satellites an Almanac can hold, one for every GPS PRN
+pub const MAX_ALMANAC_SATELLITES: usize = 32;
+
+/// A full almanac set, assembled from all ALM sentences of a cycle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Almanac {
+    entries: [Option<AlmData>; MAX_ALMANAC_SATELLITES],
+}
+
+impl Almanac {
+    fn new() -> Self {
+        Almanac {
+            entries: [None; MAX_ALMANAC_SATELLITES],
+        }
+    }
+
+    /// Returns the almanac of the satellite with the given PRN if it was sent
+    pub fn get(&self, sat_id: u8) -> Option<&AlmData> {
+        match sat_id {
+            1..=32 => self.entries[usize::from(sat_id) - 1].as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Returns the almanacs of all satellites ordered by their PRN
+    pub fn satellites(&self) -> impl Iterator<Item = &AlmData> {
+        self.entries.iter().flatten()
+    }
+
+    fn insert(&mut self, data: &AlmData) -> Result<(), AssemblyError> {
+        let sat_id = data.sat_id.ok_or(AssemblyError::MissingFieldError)?;
+        match sat_id {
+            1..=32 => {
+                self.entries[usize::from(sat_id) - 1] = Some(*data);
+                Ok(())
+            }
+            _ => Err(AssemblyError::InvalidDataError),
+        }
+    }
+}
+
+/// Collects the ALM sentences of a cycle until it is complete and turns them
+/// into an Almanac, e.g. to warm start another receiver with it. Works on
+/// fixed capacity storage so it does not require any allocations.
+#[derive(Debug, Clone)]
+pub struct AlmanacCollector {
+    almanac: SequenceTracker<(), Almanac, 1>,
+}
+
+impl Default for AlmanacCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AlmanacCollector {
+    /// Generates a new collector without an almanac in progress
+    pub fn new() -> Self {
+        AlmanacCollector {
+            almanac: SequenceTracker::new(),
+        }
+    }
+
+    /// Discards the almanac that is in progress
+    pub fn reset(&mut self) {
+        self.almanac.reset();
+    }
+
+    /// Adds an ALM sentence to the almanac, returns the Almanac once the last
+    /// sentence of the cycle has been added.
+    ///
+    /// If a sentence does not continue the almanac in progress the almanac is
+    /// discarded and a SentenceOrderError is returned. A first sentence always
+    /// starts a new almanac though, so it is kept even if it caused the error.
+    pub fn push(&mut self, data: &AlmData) -> Result<Option<Almanac>, AssemblyError> {
+        let (number_of_sentences, sentence_num) =
+            sentence_numbers(data.number_of_sentences, data.sentence_num)?;
+
+        self.almanac.push(
+            (),
+            number_of_sentences,
+            sentence_num,
+            Almanac::new,
+            |almanac| almanac.insert(data),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::alm::parse_alm;
+
+    fn alm(data: &'static [u8]) -> AlmData {
+        parse_alm(data).unwrap().1
+    }
+
+    #[test]
+    fn collects_a_complete_cycle() {
+        let mut collector = AlmanacCollector::new();
+        let sentences: [&'static [u8]; 3] = [
+            b"3,1,07,1159,00,441d,4e,16be,fd5e,a10c9f,4a2da4,686e81,58cbe1,0a4,001*",
+            b"3,2,02,1159,00,5d6b,4e,0f4f,fd4a,a10d82,64a4a5,c1d25e,f5b4c2,023,7fd*",
+            b"3,3,32,1159,3f,2c63,4e,0417,fd3c,a10c5b,8a2b2c,3f3b33,d8e33a,03b,000*",
+        ];
+        assert_eq!(collector.push(&alm(sentences[0])), Ok(None));
+        assert_eq!(collector.push(&alm(sentences[1])), Ok(None));
+
+        let almanac = collector.push(&alm(sentences[2])).unwrap().unwrap();
+        assert_eq!(almanac.get(2), Some(&alm(sentences[1])));
+        assert_eq!(almanac.get(32).and_then(|data| data.health), Some(0x3f));
+        assert_eq!(almanac.get(3), None);
+        assert_eq!(almanac.get(33), None);
+
+        let mut sat_ids = almanac.satellites().map(|data| data.sat_id);
+        assert_eq!(sat_ids.next(), Some(Some(2)));
+        assert_eq!(sat_ids.next(), Some(Some(7)));
+        assert_eq!(sat_ids.next(), Some(Some(32)));
+        assert_eq!(sat_ids.next(), None);
+    }
+
+    #[test]
+    fn rejects_satellite_ids_outside_of_the_gps_prns() {
+        let mut collector = AlmanacCollector::new();
+        assert_eq!(
+            collector.push(&alm(
+                b"1,1,00,1159,00,441d,4e,16be,fd5e,a10c9f,4a2da4,686e81,58cbe1,0a4,001*"
+            )),
+            Err(AssemblyError::InvalidDataError)
+        );
+
+        assert_eq!(
+            collector.push(&alm(
+                b"2,1,01,1159,00,441d,4e,16be,fd5e,a10c9f,4a2da4,686e81,58cbe1,0a4,001*"
+            )),
+            Ok(None)
+        );
+        assert_eq!(
+            collector.push(&alm(
+                b"2,2,33,1159,00,441d,4e,16be,fd5e,a10c9f,4a2da4,686e81,58cbe1,0a4,001*"
+            )),
+            Err(AssemblyError::InvalidDataError)
+        );
+        // The almanac was discarded
+        assert_eq!(
+            collector.push(&alm(
+                b"2,2,02,1159,00,441d,4e,16be,fd5e,a10c9f,4a2da4,686e81,58cbe1,0a4,001*"
+            )),
+            Err(AssemblyError::SentenceOrderError {
+                expected: 1,
+                received: 2
+            })
+        );
+    }
+
+    #[test]
+    fn requires_the_satellite_id() {
+        let mut collector = AlmanacCollector::new();
+        assert_eq!(
+            collector.push(&alm(b"1,1,,1159,,,,,,,,,,,*")),
+            Err(AssemblyError::MissingFieldError)
+        );
+    }
+}
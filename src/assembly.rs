@@ -0,0 +1,297 @@
+use crate::errors::AssemblyError;
+
+/// Checks the number of sentences and the sentence number of a sentence that
+/// is part of a multi sentence message and returns them in that order
+pub(crate) fn sentence_numbers(
+    number_of_sentences: Option<u16>,
+    sentence_num: Option<u16>,
+) -> Result<(u16, u16), AssemblyError> {
+    let (number_of_sentences, sentence_num) = match (number_of_sentences, sentence_num) {
+        (Some(number_of_sentences), Some(sentence_num)) => (number_of_sentences, sentence_num),
+        _ => return Err(AssemblyError::MissingFieldError),
+    };
+
+    if sentence_num == 0 || sentence_num > number_of_sentences {
+        return Err(AssemblyError::SentenceCountError);
+    }
+
+    Ok((number_of_sentences, sentence_num))
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Sequence<K, T> {
+    key: K,
+    value: T,
+    number_of_sentences: u16,
+    next_sentence: u16,
+}
+
+/// Keeps track of up to N multi sentence messages that are received at the
+/// same time and assembles each of them into a value of type T. Messages are
+/// told apart by a key, e.g. the talker that sent them.
+#[derive(Debug, Clone)]
+pub(crate) struct SequenceTracker<K, T, const N: usize> {
+    sequences: [Option<Sequence<K, T>>; N],
+}
+
+impl<K: Copy + PartialEq, T: Copy, const N: usize> SequenceTracker<K, T, N> {
+    /// Generates a new tracker without any messages in progress
+    pub(crate) fn new() -> Self {
+        SequenceTracker {
+            sequences: [None; N],
+        }
+    }
+
+    /// Discards all messages that are in progress
+    pub(crate) fn reset(&mut self) {
+        self.sequences = [None; N];
+    }
+
+    /// Adds a sentence to the message with the given key. A first sentence
+    /// starts the message with the value created by start, the sentence is
+    /// then added to the value with add. Returns the value once the last
+    /// sentence of the message has been added.
+    ///
+    /// If a sentence does not continue the message in progress the message is
+    /// discarded and a SentenceOrderError is returned. A first sentence always
+    /// starts a new message though, so it is kept even if it caused the error.
+    /// Messages add fails for are discarded as well.
+    pub(crate) fn push<S, A>(
+        &mut self,
+        key: K,
+        number_of_sentences: u16,
+        sentence_num: u16,
+        start: S,
+        add: A,
+    ) -> Result<Option<T>, AssemblyError>
+    where
+        S: FnOnce() -> T,
+        A: FnOnce(&mut T) -> Result<(), AssemblyError>,
+    {
+        let index = self.slot(key)?;
+        let (expected, in_order) = match &self.sequences[index] {
+            Some(sequence) => (
+                sequence.next_sentence,
+                sequence.next_sentence == sentence_num
+                    && sequence.number_of_sentences == number_of_sentences,
+            ),
+            None => (1, sentence_num == 1),
+        };
+        let order_error = AssemblyError::SentenceOrderError {
+            expected,
+            received: sentence_num,
+        };
+
+        if sentence_num == 1 {
+            self.sequences[index] = Some(Sequence {
+                key,
+                value: start(),
+                number_of_sentences,
+                next_sentence: 1,
+            });
+        } else if !in_order {
+            self.sequences[index] = None;
+            return Err(order_error);
+        }
+
+        let sequence = match &mut self.sequences[index] {
+            Some(sequence) => sequence,
+            None => return Err(order_error),
+        };
+
+        if let Err(error) = add(&mut sequence.value) {
+            self.sequences[index] = None;
+            return Err(error);
+        }
+        sequence.next_sentence += 1;
+
+        if sentence_num == number_of_sentences {
+            let value = sequence.value;
+            self.sequences[index] = None;
+            return Ok(Some(value));
+        }
+
+        if in_order {
+            Ok(None)
+        } else {
+            Err(order_error)
+        }
+    }
+
+    /// Finds the message with the given key or a free slot if there is none yet
+    fn slot(&self, key: K) -> Result<usize, AssemblyError> {
+        let mut free = None;
+        for (index, sequence) in self.sequences.iter().enumerate() {
+            match sequence {
+                Some(sequence) if sequence.key == key => return Ok(index),
+                None if free.is_none() => free = Some(index),
+                _ => {}
+            }
+        }
+
+        free.ok_or(AssemblyError::CapacityError)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Assembles the sum of the values of all sentences
+    type Tracker = SequenceTracker<u8, u32, 2>;
+
+    fn push(
+        tracker: &mut Tracker,
+        key: u8,
+        number_of_sentences: u16,
+        sentence_num: u16,
+        value: u32,
+    ) -> Result<Option<u32>, AssemblyError> {
+        tracker.push(
+            key,
+            number_of_sentences,
+            sentence_num,
+            || 0,
+            |sum| {
+                *sum += value;
+                Ok(())
+            },
+        )
+    }
+
+    #[test]
+    fn checks_sentence_numbers() {
+        assert_eq!(sentence_numbers(Some(3), Some(2)), Ok((3, 2)));
+        assert_eq!(
+            sentence_numbers(Some(3), None),
+            Err(AssemblyError::MissingFieldError)
+        );
+        assert_eq!(
+            sentence_numbers(None, Some(1)),
+            Err(AssemblyError::MissingFieldError)
+        );
+        assert_eq!(
+            sentence_numbers(Some(3), Some(0)),
+            Err(AssemblyError::SentenceCountError)
+        );
+        assert_eq!(
+            sentence_numbers(Some(3), Some(4)),
+            Err(AssemblyError::SentenceCountError)
+        );
+    }
+
+    #[test]
+    fn assembles_a_complete_message() {
+        let mut tracker = Tracker::new();
+        assert_eq!(push(&mut tracker, 0, 3, 1, 1), Ok(None));
+        assert_eq!(push(&mut tracker, 0, 3, 2, 10), Ok(None));
+        assert_eq!(push(&mut tracker, 0, 3, 3, 100), Ok(Some(111)));
+        assert_eq!(push(&mut tracker, 0, 1, 1, 5), Ok(Some(5)));
+    }
+
+    #[test]
+    fn rejects_sentences_out_of_order() {
+        let mut tracker = Tracker::new();
+        assert_eq!(
+            push(&mut tracker, 0, 3, 2, 10),
+            Err(AssemblyError::SentenceOrderError {
+                expected: 1,
+                received: 2
+            })
+        );
+
+        assert_eq!(push(&mut tracker, 0, 3, 1, 1), Ok(None));
+        assert_eq!(
+            push(&mut tracker, 0, 3, 3, 100),
+            Err(AssemblyError::SentenceOrderError {
+                expected: 2,
+                received: 3
+            })
+        );
+        // The message was discarded, so its second sentence is out of order as well
+        assert_eq!(
+            push(&mut tracker, 0, 3, 2, 10),
+            Err(AssemblyError::SentenceOrderError {
+                expected: 1,
+                received: 2
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_a_changed_number_of_sentences() {
+        let mut tracker = Tracker::new();
+        assert_eq!(push(&mut tracker, 0, 3, 1, 1), Ok(None));
+        assert_eq!(
+            push(&mut tracker, 0, 2, 2, 10),
+            Err(AssemblyError::SentenceOrderError {
+                expected: 2,
+                received: 2
+            })
+        );
+    }
+
+    #[test]
+    fn restarts_the_message_on_a_first_sentence() {
+        let mut tracker = Tracker::new();
+        assert_eq!(push(&mut tracker, 0, 2, 1, 1), Ok(None));
+        assert_eq!(
+            push(&mut tracker, 0, 2, 1, 2),
+            Err(AssemblyError::SentenceOrderError {
+                expected: 2,
+                received: 1
+            })
+        );
+        assert_eq!(push(&mut tracker, 0, 2, 2, 20), Ok(Some(22)));
+    }
+
+    #[test]
+    fn assembles_interleaved_messages() {
+        let mut tracker = Tracker::new();
+        assert_eq!(push(&mut tracker, 0, 2, 1, 1), Ok(None));
+        assert_eq!(push(&mut tracker, 1, 2, 1, 2), Ok(None));
+        assert_eq!(push(&mut tracker, 1, 2, 2, 20), Ok(Some(22)));
+        assert_eq!(push(&mut tracker, 0, 2, 2, 10), Ok(Some(11)));
+    }
+
+    #[test]
+    fn reports_capacity_overflow() {
+        let mut tracker = Tracker::new();
+        assert_eq!(push(&mut tracker, 0, 2, 1, 1), Ok(None));
+        assert_eq!(push(&mut tracker, 1, 2, 1, 2), Ok(None));
+        assert_eq!(
+            push(&mut tracker, 2, 2, 1, 3),
+            Err(AssemblyError::CapacityError)
+        );
+
+        // Completing a message frees its slot again
+        assert_eq!(push(&mut tracker, 0, 2, 2, 10), Ok(Some(11)));
+        assert_eq!(push(&mut tracker, 2, 2, 1, 3), Ok(None));
+
+        tracker.reset();
+        assert_eq!(
+            push(&mut tracker, 1, 2, 2, 20),
+            Err(AssemblyError::SentenceOrderError {
+                expected: 1,
+                received: 2
+            })
+        );
+    }
+
+    #[test]
+    fn discards_the_message_if_adding_a_sentence_fails() {
+        let mut tracker = Tracker::new();
+        assert_eq!(push(&mut tracker, 0, 3, 1, 1), Ok(None));
+        assert_eq!(
+            tracker.push(0, 3, 2, || 0, |_| Err(AssemblyError::CapacityError)),
+            Err(AssemblyError::CapacityError)
+        );
+        assert_eq!(
+            push(&mut tracker, 0, 3, 3, 100),
+            Err(AssemblyError::SentenceOrderError {
+                expected: 1,
+                received: 3
+            })
+        );
+    }
+}
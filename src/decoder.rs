@@ -0,0 +1,319 @@
+use crate::errors::NmeaSentenceError;
+use crate::parse::SentenceData;
+use crate::parse_nmea_sentence;
+use crate::sentences::{MAX_PROPRIETARY_SENTENCE_LENGTH, MAX_SENTENCE_LENGTH};
+
+/// An incremental decoder that frames NMEA sentences out of a raw byte stream
+/// such as the one of a serial port. It works on a fixed capacity buffer and
+/// therefore does not require any allocations.
+///
+/// Bytes outside of a sentence are discarded, the decoder resynchronises on
+/// every `$` or `!` it encounters.
+///
+/// The buffer holds CAPACITY bytes, which by default is just enough for the
+/// sentences of NMEA 0183 itself. Proprietary sentences are allowed to be
+/// longer, a decoder that should receive them in full can be created with
+/// `NmeaDecoder::<MAX_PROPRIETARY_SENTENCE_LENGTH>::with_capacity()`.
+///
+/// Sentences are parsed like parse_nmea_sentence does, so proprietary ones
+/// only with `BUILTIN_PROPRIETARY_PARSERS`.
+#[derive(Debug, Clone)]
+pub struct NmeaDecoder<const CAPACITY: usize = MAX_SENTENCE_LENGTH> {
+    buffer: [u8; CAPACITY],
+    length: usize,
+    in_sentence: bool,
+    /// Set once the \r of the line ending has been received
+    carriage_return: bool,
+}
+
+impl<const CAPACITY: usize> Default for NmeaDecoder<CAPACITY> {
+    fn default() -> Self {
+        Self::with_capacity()
+    }
+}
+
+impl NmeaDecoder {
+    /// Generates a new decoder with the default capacity which waits for
+    /// the start of a sentence
+    pub fn new() -> Self {
+        Self::with_capacity()
+    }
+}
+
+impl<const CAPACITY: usize> NmeaDecoder<CAPACITY> {
+    /// Generates a new decoder buffering sentences of up to CAPACITY bytes
+    /// which waits for the start of a sentence
+    pub fn with_capacity() -> Self {
+        NmeaDecoder {
+            buffer: [0; CAPACITY],
+            length: 0,
+            in_sentence: false,
+            carriage_return: false,
+        }
+    }
+
+    /// Discards the partially received sentence, if there is one
+    pub fn reset(&mut self) {
+        self.length = 0;
+        self.in_sentence = false;
+        self.carriage_return = false;
+    }
+
+    /// Pushes a single byte into the decoder, returns a result as soon as
+    /// the line ending of a sentence has been received or a framing error
+    /// was detected. The line ending is not buffered and does not count
+    /// towards the length limit.
+    ///
+    /// Framing errors are reported as follows:
+    /// * `FramingError` if a sentence is interrupted by the start of a new one
+    ///   or contains a control character, this includes a \r that is not
+    ///   followed by a \n
+    /// * `SentenceLengthError` if a sentence exceeds `MAX_SENTENCE_LENGTH`, or
+    ///   `MAX_PROPRIETARY_SENTENCE_LENGTH` for proprietary ones, or does not fit
+    ///   into the buffer, the rest of it is discarded until the next start character
+    pub fn push(&mut self, byte: u8) -> Option<Result<SentenceData<'_>, NmeaSentenceError<'_>>> {
+        match byte {
+            b'$' | b'!' => {
+                let interrupted = self.in_sentence;
+                self.buffer[0] = byte;
+                self.length = 1;
+                self.in_sentence = true;
+                self.carriage_return = false;
+
+                if interrupted {
+                    Some(Err(NmeaSentenceError::FramingError))
+                } else {
+                    None
+                }
+            }
+            _ if !self.in_sentence => None,
+            b'\n' => {
+                let length = self.length;
+                self.reset();
+                Some(parse_nmea_sentence(&self.buffer[..length]))
+            }
+            b'\r' if !self.carriage_return => {
+                self.carriage_return = true;
+                None
+            }
+            byte if self.carriage_return || !(0x20..=0x7e).contains(&byte) => {
+                self.reset();
+                Some(Err(NmeaSentenceError::FramingError))
+            }
+            _ => {
+                let max_length = self.max_length();
+                if self.length == max_length {
+                    self.reset();
+                    return Some(Err(NmeaSentenceError::SentenceLengthError(max_length + 1)));
+                }
+
+                self.buffer[self.length] = byte;
+                self.length += 1;
+                None
+            }
+        }
+    }
+
+    /// The length limit of the sentence that is being received
+    fn max_length(&self) -> usize {
+        let max_length = if self.length > 1 && self.buffer[1] == b'P' {
+            MAX_PROPRIETARY_SENTENCE_LENGTH
+        } else {
+            MAX_SENTENCE_LENGTH
+        };
+        max_length.min(CAPACITY)
+    }
+
+    /// Pushes a whole slice of bytes into the decoder, the callback is invoked
+    /// with every sentence or framing error in the order they were encountered.
+    pub fn feed<F>(&mut self, bytes: &[u8], mut callback: F)
+    where
+        F: FnMut(Result<SentenceData<'_>, NmeaSentenceError<'_>>),
+    {
+        for byte in bytes {
+            if let Some(result) = self.push(*byte) {
+                callback(result);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+    use super::*;
+    use crate::sentences::SentenceType;
+    use std::vec::Vec;
+
+    const VTG: &[u8] = b"$GPVTG,054.7,T,034.4,M,005.5,N,010.2,K*48\r\n";
+    const ROT: &[u8] = b"$HEROT,-11.3,A*35\r\n";
+    /// Exactly as long as a non proprietary sentence may be, without the line ending
+    const LONGEST: &[u8] = b"$GPGGA,092750.000,5321.6802000000000000000,N,00630.33720000000000000000,W,1,08,1.03,61.7,M,55.2,M,,*76";
+    const VDM: &[u8] = b"!AIVDM,1,1,,B,177KQJ5000G?tO`K>RA1wUbN0TKH,0*5C\r\n";
+    const PUBX: &[u8] = b"$PUBX,03,11,23,-,,,45,010,29,-,,,46,013,07,-,,,42,015,08,U,067,31,42,025,10,U,195,33,46,026,18,U,326,08,39,026,17,-,,,32,015,26,U,306,66,48,025,27,U,073,10,36,026,28,U,089,61,46,024,15,-,,,39,014*0D\r\n";
+
+    fn decode<const CAPACITY: usize>(
+        decoder: &mut NmeaDecoder<CAPACITY>,
+        bytes: &[u8],
+    ) -> Vec<Result<SentenceType, NmeaSentenceError<'static>>> {
+        let mut results = Vec::new();
+        decoder.feed(bytes, |result| {
+            results.push(match result {
+                Ok(data) => Ok(data.sentence_type()),
+                Err(NmeaSentenceError::FramingError) => Err(NmeaSentenceError::FramingError),
+                Err(NmeaSentenceError::SentenceLengthError(length)) => {
+                    Err(NmeaSentenceError::SentenceLengthError(length))
+                }
+                Err(error) => panic!("unexpected error {:?}", error),
+            })
+        });
+        results
+    }
+
+    fn concat(parts: &[&[u8]]) -> Vec<u8> {
+        parts.iter().flat_map(|part| part.iter().copied()).collect()
+    }
+
+    #[test]
+    fn discards_bytes_outside_of_sentences() {
+        let mut decoder = NmeaDecoder::new();
+        let stream = concat(&[b"garbage", VTG, b"\x00\xffmore garbage\r\n", ROT]);
+        assert_eq!(
+            decode(&mut decoder, &stream),
+            [Ok(SentenceType::VTG), Ok(SentenceType::ROT)]
+        );
+    }
+
+    #[test]
+    fn frames_sentences_split_across_pushes() {
+        let mut decoder = NmeaDecoder::new();
+        for byte in &VTG[..VTG.len() - 1] {
+            assert!(decoder.push(*byte).is_none());
+        }
+        match decoder.push(b'\n') {
+            Some(Ok(data)) => assert_eq!(data.sentence_type(), SentenceType::VTG),
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    #[test]
+    fn accepts_lines_terminated_by_newline_only() {
+        let mut decoder = NmeaDecoder::new();
+        assert_eq!(
+            decode(&mut decoder, b"$HEROT,-11.3,A*35\n"),
+            [Ok(SentenceType::ROT)]
+        );
+    }
+
+    #[test]
+    fn resynchronises_on_start_characters() {
+        let mut decoder = NmeaDecoder::new();
+        let stream = concat(&[b"garbage", VTG, b"$GPGG", ROT, b"$GPGGA,0927", VDM]);
+        assert_eq!(
+            decode(&mut decoder, &stream),
+            [
+                Ok(SentenceType::VTG),
+                Err(NmeaSentenceError::FramingError),
+                Ok(SentenceType::ROT),
+                Err(NmeaSentenceError::FramingError),
+                Ok(SentenceType::VDM),
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_control_characters() {
+        let mut decoder = NmeaDecoder::new();
+        let stream = concat(&[b"$GPVTG,054.7,T\x07,034.4,M*48\r\n", ROT]);
+        assert_eq!(
+            decode(&mut decoder, &stream),
+            [Err(NmeaSentenceError::FramingError), Ok(SentenceType::ROT)]
+        );
+    }
+
+    #[test]
+    fn discards_over_long_lines() {
+        let mut decoder = NmeaDecoder::new();
+        let mut stream = b"$GPGGA,".to_vec();
+        stream.extend(core::iter::repeat_n(b'1', 120));
+        stream.extend_from_slice(b"*00\r\n");
+        stream.extend_from_slice(ROT);
+        assert_eq!(
+            decode(&mut decoder, &stream),
+            [
+                Err(NmeaSentenceError::SentenceLengthError(
+                    MAX_SENTENCE_LENGTH + 1
+                )),
+                Ok(SentenceType::ROT),
+            ]
+        );
+    }
+
+    #[test]
+    fn does_not_count_the_line_ending_towards_the_limit() {
+        let mut decoder = NmeaDecoder::new();
+        assert_eq!(LONGEST.len(), MAX_SENTENCE_LENGTH);
+        let stream = concat(&[LONGEST, b"\r\n", LONGEST, b"\n"]);
+        assert_eq!(
+            decode(&mut decoder, &stream),
+            [Ok(SentenceType::GGA), Ok(SentenceType::GGA)]
+        );
+    }
+
+    #[test]
+    fn rejects_carriage_return_inside_a_sentence() {
+        let mut decoder = NmeaDecoder::new();
+        let stream = concat(&[b"$HEROT,-11.3\r,A*35\r\n", ROT]);
+        assert_eq!(
+            decode(&mut decoder, &stream),
+            [Err(NmeaSentenceError::FramingError), Ok(SentenceType::ROT)]
+        );
+    }
+
+    #[test]
+    fn limits_proprietary_sentences_to_the_capacity() {
+        let mut decoder = NmeaDecoder::new();
+        assert!(PUBX.len() > MAX_SENTENCE_LENGTH);
+        let stream = concat(&[PUBX, ROT]);
+        assert_eq!(
+            decode(&mut decoder, &stream),
+            [
+                Err(NmeaSentenceError::SentenceLengthError(
+                    MAX_SENTENCE_LENGTH + 1
+                )),
+                Ok(SentenceType::ROT),
+            ]
+        );
+
+        let mut decoder = NmeaDecoder::<24>::with_capacity();
+        let stream = concat(&[VTG, ROT]);
+        assert_eq!(
+            decode(&mut decoder, &stream),
+            [
+                Err(NmeaSentenceError::SentenceLengthError(25)),
+                Ok(SentenceType::ROT),
+            ]
+        );
+    }
+
+    #[test]
+    fn buffers_long_proprietary_sentences() {
+        let mut decoder = NmeaDecoder::<MAX_PROPRIETARY_SENTENCE_LENGTH>::with_capacity();
+        assert_eq!(decode(&mut decoder, PUBX), [Ok(SentenceType::Proprietary)]);
+        assert_eq!(decode(&mut decoder, PUBX), [Ok(SentenceType::Proprietary)]);
+
+        let mut stream = b"$PUBX,03,".to_vec();
+        stream.extend(core::iter::repeat_n(b'1', MAX_PROPRIETARY_SENTENCE_LENGTH));
+        stream.extend_from_slice(b"*00\r\n");
+        stream.extend_from_slice(ROT);
+        assert_eq!(
+            decode(&mut decoder, &stream),
+            [
+                Err(NmeaSentenceError::SentenceLengthError(
+                    MAX_PROPRIETARY_SENTENCE_LENGTH + 1
+                )),
+                Ok(SentenceType::ROT),
+            ]
+        );
+    }
+}
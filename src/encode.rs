@@ -0,0 +1,268 @@
+use crate::encoders::{self, ChecksumWriter, SliceWriter};
+use crate::errors::NmeaEncodeError;
+use crate::parse::SentenceData;
+use crate::sentences::Talker;
+use core::fmt::Write;
+
+macro_rules! sentence_encode_generator {
+    ($writer:ident, $data:ident : [$($TYPE:ident => $function:path,)+]) => {
+        match $data {
+            $(
+                SentenceData::$TYPE(data) => $function($writer, data)?,
+            )+
+            _ => return Err(NmeaEncodeError::TypeNotImplementedError($data.sentence_type())),
+        }
+    }
+}
+
+/// Encodes the given data into a complete NMEA sentence including the checksum
+/// and the terminating \r\n, encapsulated sentences start with a `!`. Proprietary
+/// talkers can not be used here.
+pub fn encode_nmea_sentence<W: Write>(
+    writer: &mut W,
+    talker: Talker,
+    data: &SentenceData,
+) -> Result<(), NmeaEncodeError> {
+    let talker = match talker.as_str() {
+        Some(id) if id.bytes().all(|byte| byte.is_ascii_uppercase()) => id,
+        _ => return Err(NmeaEncodeError::TalkerError),
+    };
+
+    writer.write_char(data.sentence_type().start_delimiter())?;
+    let mut checksum_writer = ChecksumWriter::new(writer);
+    write!(
+        checksum_writer,
+        "{}{},",
+        talker,
+        data.sentence_type().as_str()
+    )?;
+    encode_sentence_data(&mut checksum_writer, data)?;
+    let checksum = checksum_writer.checksum;
+
+    write!(writer, "*{:02X}\r\n", checksum)?;
+    Ok(())
+}
+
+/// Encodes the given data into the buffer, see encode_nmea_sentence.
+/// Returns the amount of bytes written on success
+pub fn encode_nmea_sentence_to_slice(
+    buffer: &mut [u8],
+    talker: Talker,
+    data: &SentenceData,
+) -> Result<usize, NmeaEncodeError> {
+    let length = buffer.len();
+    let mut writer = SliceWriter::new(buffer);
+
+    match encode_nmea_sentence(&mut writer, talker, data) {
+        Ok(()) => Ok(writer.position),
+        Err(_) if writer.overflow => Err(NmeaEncodeError::BufferLengthError(length)),
+        Err(error) => Err(error),
+    }
+}
+
+fn encode_sentence_data<W: Write>(
+    writer: &mut W,
+    data: &SentenceData,
+) -> Result<(), NmeaEncodeError> {
+    #[cfg(feature = "alloc")]
+    {
+        if let SentenceData::GNS(data) = data {
+            encoders::gns::encode_gns(writer, data)?;
+            return Ok(());
+        }
+    }
+
+    sentence_encode_generator!(
+        writer, data: [
+            AAM => encoders::aam::encode_aam,
+            ABK => encoders::abk::encode_abk,
+            ACK => encoders::ack::encode_ack,
+            ALM => encoders::alm::encode_alm,
+            APA => encoders::apa::encode_apa,
+            APB => encoders::apb::encode_apb,
+            BEC => encoders::bwc::encode_bwc,
+            BOD => encoders::bod::encode_bod,
+            BWC => encoders::bwc::encode_bwc,
+            BWR => encoders::bwc::encode_bwc,
+            BWW => encoders::bod::encode_bod,
+            DBK => encoders::depth::encode_depth,
+            DBS => encoders::depth::encode_depth,
+            DBT => encoders::depth::encode_depth,
+            DCN => encoders::dcn::encode_dcn,
+            DPT => encoders::dpt::encode_dpt,
+            DTM => encoders::dtm::encode_dtm,
+            FSI => encoders::fsi::encode_fsi,
+            GBS => encoders::gbs::encode_gbs,
+            GGA => encoders::gga::encode_gga,
+            GLC => encoders::glc::encode_glc,
+            GLL => encoders::gll::encode_gll,
+            GRS => encoders::grs::encode_grs,
+            GSA => encoders::gsa::encode_gsa,
+            GST => encoders::gst::encode_gst,
+            GSV => encoders::gsv::encode_gsv,
+            GTD => encoders::gtd::encode_gtd,
+            GXA => encoders::gxa::encode_gxa,
+            HDG => encoders::hdg::encode_hdg,
+            HDM => encoders::hdm::encode_hdm,
+            HDT => encoders::hdt::encode_hdt,
+            HSC => encoders::hsc::encode_hsc,
+            LCD => encoders::lcd::encode_lcd,
+            MDA => encoders::mda::encode_mda,
+            MSK => encoders::msk::encode_msk,
+            MTW => encoders::mtw::encode_mtw,
+            MWD => encoders::mwd::encode_mwd,
+            MWV => encoders::mwv::encode_mwv,
+            OLN => encoders::oln::encode_oln,
+            OSD => encoders::osd::encode_osd,
+            RMA => encoders::rma::encode_rma,
+            RMB => encoders::rmb::encode_rmb,
+            RMC => encoders::rmc::encode_rmc,
+            ROO => encoders::roo::encode_roo,
+            ROT => encoders::rot::encode_rot,
+            RPM => encoders::rpm::encode_rpm,
+            RSA => encoders::rsa::encode_rsa,
+            RSD => encoders::rsd::encode_rsd,
+            RTE => encoders::rte::encode_rte,
+            SFI => encoders::sfi::encode_sfi,
+            STN => encoders::stn::encode_stn,
+            TLL => encoders::tll::encode_tll,
+            TTM => encoders::ttm::encode_ttm,
+            VBW => encoders::vbw::encode_vbw,
+            VDM => encoders::vdm::encode_vdm,
+            VDO => encoders::vdm::encode_vdm,
+            VDR => encoders::vdr::encode_vdr,
+            VHW => encoders::vhw::encode_vhw,
+            VLW => encoders::vlw::encode_vlw,
+            VPW => encoders::vpw::encode_vpw,
+            VTG => encoders::vtg::encode_vtg,
+            VWR => encoders::vwr::encode_vwr,
+            WCV => encoders::wcv::encode_wcv,
+            WNC => encoders::wnc::encode_wnc,
+            WPL => encoders::wpl::encode_wpl,
+            XDR => encoders::xdr::encode_xdr,
+            XTE => encoders::xte::encode_xte,
+            XTR => encoders::xtr::encode_xtr,
+            ZDA => encoders::zda::encode_zda,
+            ZFO => encoders::zfo::encode_zfo,
+            ZTG => encoders::ztg::encode_ztg,
+        ]
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::GpsTime;
+    use crate::parse_nmea_sentence;
+
+    fn encode<'b>(data: &SentenceData, buffer: &'b mut [u8]) -> &'b [u8] {
+        let length = encode_nmea_sentence_to_slice(buffer, Talker::GP, data).unwrap();
+        &buffer[..length]
+    }
+
+    /// Parses the sentence, encodes it again and checks that the encoded
+    /// sentence parses into the very same data and encodes unchanged
+    fn round_trip(sentence: &[u8]) {
+        let data = parse_nmea_sentence(sentence).unwrap();
+        let mut buffer = [0; 102];
+        let encoded = encode(&data, &mut buffer);
+        let reparsed = parse_nmea_sentence(encoded).unwrap();
+        assert_eq!(reparsed, data);
+
+        let mut buffer_again = [0; 102];
+        assert_eq!(encode(&reparsed, &mut buffer_again), encoded);
+    }
+
+    #[test]
+    fn round_trips_gga() {
+        round_trip(b"$GPGGA,092750.29,5321.6802,N,00630.3372,W,1,08,1.03,61.7,M,55.2,M,,*7D\r\n");
+    }
+
+    #[test]
+    fn round_trips_rmc() {
+        round_trip(b"$GPRMC,235959.99,A,4807.0380,N,01131.0000,E,22.4,84.4,230394,3.1,W*48\r\n");
+    }
+
+    #[test]
+    fn round_trips_gsv() {
+        round_trip(b"$GPGSV,3,1,11,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,43*73\r\n");
+        round_trip(b"$GPGSV,3,3,11,22,42,067,42,24,14,311,*78\r\n");
+    }
+
+    #[test]
+    fn round_trips_gsa() {
+        round_trip(b"$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39\r\n");
+    }
+
+    #[test]
+    fn round_trips_sfi() {
+        round_trip(b"$GPSFI,1,1,021450,d,021460,e*49\r\n");
+        round_trip(b"$GPSFI,2,2,156800,,021450,d*27\r\n");
+        round_trip(b"$GPSFI,1,1*4B\r\n");
+        round_trip(b"$GPSFI,,*4B\r\n");
+    }
+
+    #[test]
+    fn keeps_empty_waypoints_in_place() {
+        let sentences: [&[u8]; 3] = [
+            b"$GPROO,WPT1,WPT2,,WPT3*26\r\n",
+            b"$GPRTE,2,1,c,0,W3IWI,,32CEDR*0A\r\n",
+            b"$GPROO,*69\r\n",
+        ];
+        for sentence in sentences.iter() {
+            let data = parse_nmea_sentence(sentence).unwrap();
+            let mut buffer = [0; 102];
+            assert_eq!(encode(&data, &mut buffer), *sentence);
+        }
+
+        match parse_nmea_sentence(sentences[0]).unwrap() {
+            SentenceData::ROO(roo) => assert_eq!(
+                crate::parse::iter_waypoints(&roo.waypoints).nth(2),
+                Some(None)
+            ),
+            data => panic!("unexpected data {:?}", data),
+        }
+    }
+
+    #[test]
+    fn encodes_only_the_scan_frequencies_given() {
+        let sentences: [&[u8]; 2] = [
+            b"$GPSFI,1,1,021450,d,021460,e*49\r\n",
+            b"$GPSFI,2,1,,,021460,e*2C\r\n",
+        ];
+        for sentence in sentences.iter() {
+            let data = parse_nmea_sentence(sentence).unwrap();
+            let mut buffer = [0; 102];
+            assert_eq!(encode(&data, &mut buffer), *sentence);
+        }
+    }
+
+    #[test]
+    fn truncates_seconds_instead_of_rounding_into_the_next_minute() {
+        let data = parse_nmea_sentence(
+            b"$GPGGA,123559.996,4916.4500,N,12311.1200,W,1,08,0.9,545.4,M,46.9,M,,*43\r\n",
+        )
+        .unwrap();
+        let mut buffer = [0; 102];
+        let encoded = encode(&data, &mut buffer);
+        assert!(encoded.starts_with(b"$GPGGA,123559.99,"));
+
+        let reparsed = parse_nmea_sentence(encoded).unwrap();
+        match &reparsed {
+            SentenceData::GGA(gga) => assert_eq!(
+                gga.time,
+                Some(GpsTime {
+                    hour: 12,
+                    minute: 35,
+                    second: 59.99,
+                })
+            ),
+            data => panic!("unexpected data {:?}", data),
+        }
+
+        let mut buffer_again = [0; 102];
+        assert_eq!(encode(&reparsed, &mut buffer_again), encoded);
+    }
+}
@@ -0,0 +1,14 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_aam<W: Write>(writer: &mut W, data: &AamData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{}",
+        Field(data.arrival_circle.map(ArrivalStatus::value)),
+        Field(data.perpendicular_passed.map(ArrivalStatus::value)),
+        Field(data.radius),
+        Field(data.radius_unit.map(DistanceUnit::value)),
+        Text(data.waypoint)
+    )
+}
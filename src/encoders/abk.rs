@@ -0,0 +1,14 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_abk<W: Write>(writer: &mut W, data: &AbkData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{}",
+        Field(data.mmsi),
+        Field(data.channel.map(AisChannel::value)),
+        Field(data.message_id),
+        Field(data.sequence_number),
+        Field(data.acknowledgement.map(AbkAcknowledgement::value))
+    )
+}
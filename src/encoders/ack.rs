@@ -0,0 +1,6 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_ack<W: Write>(writer: &mut W, data: &AckData) -> fmt::Result {
+    write!(writer, "{}", Field(data.alarm_id))
+}
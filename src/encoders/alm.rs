@@ -0,0 +1,41 @@
+use super::utils::*;
+use crate::parse::*;
+
+/// Writes an almanac word of the given amount of bits as hex,
+/// signed words are cut down to their width
+struct Hex(Option<u32>, u32);
+
+impl Display for Hex {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0 {
+            Some(value) => {
+                let mask = (1u64 << self.1) - 1;
+                let digits = (self.1 as usize).div_ceil(4);
+                write!(f, "{:0width$X}", u64::from(value) & mask, width = digits)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+pub(crate) fn encode_alm<W: Write>(writer: &mut W, data: &AlmData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+        Field(data.number_of_sentences),
+        Field(data.sentence_num),
+        Field(data.sat_id),
+        Field(data.week),
+        Hex(data.health.map(u32::from), 8),
+        Hex(data.eccentricity.map(u32::from), 16),
+        Hex(data.reference_time.map(u32::from), 8),
+        Hex(data.inclination_offset.map(|raw| raw as u32), 16),
+        Hex(data.rate_of_right_ascension.map(|raw| raw as u32), 16),
+        Hex(data.sqrt_semi_major_axis, 24),
+        Hex(data.argument_of_perigee.map(|raw| raw as u32), 24),
+        Hex(data.longitude_of_ascending_node.map(|raw| raw as u32), 24),
+        Hex(data.mean_anomaly.map(|raw| raw as u32), 24),
+        Hex(data.clock_bias.map(|raw| raw as u32), 11),
+        Hex(data.clock_drift.map(|raw| raw as u32), 11)
+    )
+}
@@ -0,0 +1,22 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_apa<W: Write>(writer: &mut W, data: &ApaData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{},{},{},{},{},{}",
+        Field(data.status.map(DataValidity::value)),
+        Field(data.cycle_lock_status.map(DataValidity::value)),
+        Field(data.cross_track_error),
+        Field(data.steer_direction.map(SteerDirection::value)),
+        Field(data.cross_track_unit.map(DistanceUnit::value)),
+        Field(data.arrival_circle.map(ArrivalStatus::value)),
+        Field(data.perpendicular_passed.map(ArrivalStatus::value)),
+        Field(data.bearing_origin_to_dest),
+        Field(
+            data.bearing_origin_to_dest_reference
+                .map(BearingReference::value)
+        ),
+        Text(data.dest_waypoint)
+    )
+}
@@ -0,0 +1,13 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_bod<W: Write>(writer: &mut W, data: &BodData) -> fmt::Result {
+    write!(
+        writer,
+        "{},T,{},M,{},{}",
+        Field(data.bearing_true),
+        Field(data.bearing_magnetic),
+        Text(data.to_waypoint),
+        Text(data.from_waypoint)
+    )
+}
@@ -0,0 +1,15 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_bwc<W: Write>(writer: &mut W, data: &BwcData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},T,{},M,{},N,{}",
+        Time(data.time),
+        Position(Some(data.waypoint_position)),
+        Field(data.bearing_true),
+        Field(data.bearing_magnetic),
+        Field(data.nautical_miles),
+        Text(data.waypoint)
+    )
+}
@@ -0,0 +1,27 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_dcn<W: Write>(writer: &mut W, data: &DcnData) -> fmt::Result {
+    let lines = [data.red, data.green, data.purple];
+
+    write!(writer, "{}", Field(data.chain_id))?;
+    for line in lines.iter() {
+        write!(
+            writer,
+            ",{},{},{}",
+            Text(line.zone),
+            Field(line.line_of_position),
+            Field(line.master_line_status.map(DataValidity::value))
+        )?;
+    }
+    for line in lines.iter() {
+        write!(writer, ",{}", Field(line.in_use.map(DataValidity::value)))?;
+    }
+
+    write!(
+        writer,
+        ",{},N,{}",
+        Field(data.position_uncertainty),
+        Field(data.fix_basis.map(DeccaFixBasis::value))
+    )
+}
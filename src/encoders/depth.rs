@@ -0,0 +1,12 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_depth<W: Write>(writer: &mut W, data: &DepthData) -> fmt::Result {
+    write!(
+        writer,
+        "{},f,{},M,{},F",
+        Field(data.feet),
+        Field(data.meters),
+        Field(data.fathoms)
+    )
+}
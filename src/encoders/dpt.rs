@@ -0,0 +1,12 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_dpt<W: Write>(writer: &mut W, data: &DptData) -> fmt::Result {
+    write!(writer, "{},{}", Field(data.depth), Field(data.offset))?;
+
+    if let Some(max_range) = data.max_range {
+        write!(writer, ",{}", max_range)?;
+    }
+
+    Ok(())
+}
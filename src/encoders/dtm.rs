@@ -0,0 +1,17 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_dtm<W: Write>(writer: &mut W, data: &DtmData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{},{},{},{}",
+        Text(data.local_datum.as_ref().map(Datum::as_bytes)),
+        Text(data.subdivision),
+        Field(data.lat_offset),
+        Field(data.lat_offset_direction.map(LatitudeDirection::value)),
+        Field(data.lon_offset),
+        Field(data.lon_offset_direction.map(LongitudeDirection::value)),
+        Field(data.alt_offset),
+        Text(data.reference_datum.as_ref().map(Datum::as_bytes))
+    )
+}
@@ -0,0 +1,13 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_fsi<W: Write>(writer: &mut W, data: &FsiData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{}",
+        Frequency(data.transmit_frequency),
+        Frequency(data.receive_frequency),
+        Field(data.mode.map(FrequencyMode::value)),
+        Field(data.power_level)
+    )
+}
@@ -0,0 +1,17 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_gbs<W: Write>(writer: &mut W, data: &GbsData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{},{},{},{}",
+        Time(data.time),
+        Field(data.lat_error),
+        Field(data.lon_error),
+        Field(data.alt_error),
+        Field(data.most_likely_failed_sat),
+        Field(data.missed_probability),
+        Field(data.bias_estimate),
+        Field(data.bias_standard_deviation)
+    )
+}
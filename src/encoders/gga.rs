@@ -0,0 +1,18 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_gga<W: Write>(writer: &mut W, data: &GgaData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{},{},M,{},M,{},{}",
+        Time(data.time),
+        Position(Some(data.position)),
+        Field(data.quality.map(GpsQuality::value)),
+        Field(data.sats_in_view),
+        Field(data.hdop),
+        Field(data.altitude),
+        Field(data.geoid_altitude),
+        Field(data.age_of_differential),
+        Field(data.differential_station_id)
+    )
+}
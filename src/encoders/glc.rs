@@ -0,0 +1,17 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_glc<W: Write>(writer: &mut W, data: &GlcData) -> fmt::Result {
+    write!(writer, "{}", Field(data.gri))?;
+
+    for td in core::iter::once(&data.master).chain(data.secondaries.iter()) {
+        write!(
+            writer,
+            ",{},{}",
+            Field(td.map(|td| td.microseconds)),
+            Field(td.and_then(|td| td.status).map(LoranStatus::value))
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_gll<W: Write>(writer: &mut W, data: &GllData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{}",
+        Position(Some(data.position)),
+        Time(data.time),
+        Field(data.status.map(GllStatus::value))
+    )
+}
@@ -0,0 +1,28 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_gns<W: Write>(writer: &mut W, data: &GnsData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},",
+        Time(data.time),
+        Position(Some(data.position))
+    )?;
+
+    if let Some(modes) = &data.mode {
+        for mode in modes {
+            writer.write_char(mode.value())?;
+        }
+    }
+
+    write!(
+        writer,
+        ",{},{},{},{},{},{}",
+        Field(data.sats_in_use),
+        Field(data.hdop),
+        Field(data.orthometric_height),
+        Field(data.geoid_seperation),
+        Field(data.age_of_differential),
+        Field(data.differential_station_id)
+    )
+}
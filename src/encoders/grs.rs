@@ -0,0 +1,17 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_grs<W: Write>(writer: &mut W, data: &GrsData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{}",
+        Time(data.time),
+        Field(data.mode.map(GrsMode::value))
+    )?;
+
+    for residual in &data.residuals {
+        write!(writer, ",{}", Field(*residual))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_gsa<W: Write>(writer: &mut W, data: &GsaData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},",
+        Field(data.selection_mode.map(GsaSelectionMode::value)),
+        Field(data.mode.map(GsaMode::value))
+    )?;
+
+    for satellite in &data.satellites {
+        write!(writer, "{},", Field(*satellite))?;
+    }
+
+    write!(
+        writer,
+        "{},{},{}",
+        Field(data.pdob),
+        Field(data.hdop),
+        Field(data.vdop)
+    )
+}
@@ -0,0 +1,17 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_gst<W: Write>(writer: &mut W, data: &GstData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{},{},{},{}",
+        Time(data.time),
+        Field(data.rms),
+        Field(data.semi_major_error),
+        Field(data.semi_minor_error),
+        Field(data.orientation),
+        Field(data.lat_error),
+        Field(data.lon_error),
+        Field(data.alt_error)
+    )
+}
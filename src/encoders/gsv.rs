@@ -0,0 +1,25 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_gsv<W: Write>(writer: &mut W, data: &GsvData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{}",
+        Field(data.number_of_sentences),
+        Field(data.sentence_num),
+        Field(data.sats_in_view)
+    )?;
+
+    for satellite in data.sats_info.iter().flatten() {
+        write!(
+            writer,
+            ",{},{},{},{}",
+            Field(satellite.sat_id),
+            Field(satellite.elevation),
+            Field(satellite.true_azimuth),
+            Field(satellite.snr)
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,14 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_gtd<W: Write>(writer: &mut W, data: &GtdData) -> fmt::Result {
+    for (index, td) in data.time_differences.iter().enumerate() {
+        if index > 0 {
+            writer.write_char(',')?;
+        }
+
+        write!(writer, "{}", Field(td.map(|td| td.microseconds)))?;
+    }
+
+    Ok(())
+}
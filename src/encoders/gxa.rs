@@ -0,0 +1,13 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_gxa<W: Write>(writer: &mut W, data: &GxaData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{}",
+        Time(data.time),
+        Position(data.position),
+        Text(data.waypoint),
+        Field(data.sat_id)
+    )
+}
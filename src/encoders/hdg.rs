@@ -0,0 +1,14 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_hdg<W: Write>(writer: &mut W, data: &HdgData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{}",
+        Field(data.heading_magnetic_sensor),
+        Field(data.deviation),
+        Field(data.deviation_direction.map(LongitudeDirection::value)),
+        Field(data.variation),
+        Field(data.variation_direction.map(LongitudeDirection::value))
+    )
+}
@@ -0,0 +1,6 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_hdm<W: Write>(writer: &mut W, data: &HdmData) -> fmt::Result {
+    write!(writer, "{},M", Field(data.heading_magnetic))
+}
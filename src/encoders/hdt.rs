@@ -0,0 +1,6 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_hdt<W: Write>(writer: &mut W, data: &HdtData) -> fmt::Result {
+    write!(writer, "{},T", Field(data.heading_true))
+}
@@ -0,0 +1,11 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_hsc<W: Write>(writer: &mut W, data: &HscData) -> fmt::Result {
+    write!(
+        writer,
+        "{},T,{},M",
+        Field(data.heading_true),
+        Field(data.heading_magnetic)
+    )
+}
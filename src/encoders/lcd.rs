@@ -0,0 +1,12 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_lcd<W: Write>(writer: &mut W, data: &LcdData) -> fmt::Result {
+    write!(writer, "{}", Field(data.gri))?;
+
+    for signal in core::iter::once(&data.master).chain(data.secondaries.iter()) {
+        write!(writer, ",{},{}", Field(signal.snr), Field(signal.ecd))?;
+    }
+
+    Ok(())
+}
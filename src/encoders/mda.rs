@@ -0,0 +1,20 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_mda<W: Write>(writer: &mut W, data: &MdaData) -> fmt::Result {
+    write!(
+        writer,
+        "{},I,{},B,{},C,{},C,{},{},{},C,{},T,{},M,{},N,{},M",
+        Field(data.pressure_inches),
+        Field(data.pressure_bars),
+        Field(data.air_temperature),
+        Field(data.water_temperature),
+        Field(data.relative_humidity),
+        Field(data.absolute_humidity),
+        Field(data.dew_point),
+        Field(data.wind_direction_true),
+        Field(data.wind_direction_magnetic),
+        Field(data.wind_speed_knots),
+        Field(data.wind_speed_mps)
+    )
+}
@@ -0,0 +1,20 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_msk<W: Write>(writer: &mut W, data: &MskData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{}",
+        Field(data.frequency),
+        Field(data.frequency_mode.map(GsaSelectionMode::value)),
+        Field(data.bit_rate),
+        Field(data.bit_rate_mode.map(GsaSelectionMode::value)),
+        Field(data.status_interval)
+    )?;
+
+    if let Some(channel) = data.channel {
+        write!(writer, ",{}", channel)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,11 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_mtw<W: Write>(writer: &mut W, data: &MtwData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{}",
+        Field(data.temperature),
+        Field(data.unit.map(TemperatureUnit::value))
+    )
+}
@@ -0,0 +1,13 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_mwd<W: Write>(writer: &mut W, data: &MwdData) -> fmt::Result {
+    write!(
+        writer,
+        "{},T,{},M,{},N,{},M",
+        Field(data.direction_true),
+        Field(data.direction_magnetic),
+        Field(data.speed_knots),
+        Field(data.speed_mps)
+    )
+}
@@ -0,0 +1,14 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_mwv<W: Write>(writer: &mut W, data: &MwvData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{}",
+        Field(data.angle),
+        Field(data.reference.map(WindReference::value)),
+        Field(data.speed),
+        Field(data.speed_unit.map(SpeedUnit::value)),
+        Field(data.status.map(DataValidity::value))
+    )
+}
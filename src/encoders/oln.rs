@@ -0,0 +1,19 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_oln<W: Write>(writer: &mut W, data: &OlnData) -> fmt::Result {
+    for (index, lane) in data.lanes.iter().enumerate() {
+        if index > 0 {
+            writer.write_char(',')?;
+        }
+        write!(
+            writer,
+            "{},{},{}",
+            Text(lane.pair),
+            Field(lane.lane),
+            Field(lane.centilane)
+        )?;
+    }
+
+    Ok(())
+}
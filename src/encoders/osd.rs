@@ -0,0 +1,18 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_osd<W: Write>(writer: &mut W, data: &OsdData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{},{},{},{},{}",
+        Field(data.heading_true),
+        Field(data.status.map(DataValidity::value)),
+        Field(data.course_true),
+        Field(data.course_reference.map(MotionReference::value)),
+        Field(data.speed),
+        Field(data.speed_reference.map(MotionReference::value)),
+        Field(data.set_true),
+        Field(data.drift),
+        Field(data.speed_unit.map(SpeedUnit::value))
+    )
+}
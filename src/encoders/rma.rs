@@ -0,0 +1,17 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_rma<W: Write>(writer: &mut W, data: &RmaData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{},{},{},{}",
+        Field(data.status.map(RmStatus::value)),
+        Position(Some(data.position)),
+        Field(data.time_diff_a.map(|td| td.microseconds)),
+        Field(data.time_diff_b.map(|td| td.microseconds)),
+        Field(data.speed),
+        Field(data.heading),
+        Field(data.magnetic_variation),
+        Field(data.magnetic_direction.map(LongitudeDirection::value))
+    )
+}
@@ -0,0 +1,19 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_rmb<W: Write>(writer: &mut W, data: &RmbData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{},{},{},{},{},{}",
+        Field(data.status.map(RmStatus::value)),
+        Field(data.cross_error),
+        Field(data.steer_direction.map(SteerDirection::value)),
+        Text(data.to_waypoint),
+        Text(data.from_waypoint),
+        Position(Some(data.dest_position)),
+        Field(data.range_to_dest),
+        Field(data.bearing),
+        Field(data.closing_velocity),
+        Field(data.arrival_status.map(ArrivalStatus::value))
+    )
+}
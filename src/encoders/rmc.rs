@@ -0,0 +1,17 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_rmc<W: Write>(writer: &mut W, data: &RmcData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{},{},{},{}",
+        Time(data.time),
+        Field(data.status.map(RmStatus::value)),
+        Position(Some(data.position)),
+        Field(data.speed),
+        Field(data.heading),
+        Date(data.date),
+        Field(data.magnetic_variation),
+        Field(data.magnetic_direction.map(LongitudeDirection::value))
+    )
+}
@@ -0,0 +1,13 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_roo<W: Write>(writer: &mut W, data: &RooData) -> fmt::Result {
+    for (index, waypoint) in iter_waypoints(&data.waypoints).enumerate() {
+        if index > 0 {
+            writer.write_char(',')?;
+        }
+        write!(writer, "{}", Text(waypoint))?;
+    }
+
+    Ok(())
+}
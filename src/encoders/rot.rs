@@ -0,0 +1,11 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_rot<W: Write>(writer: &mut W, data: &RotData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{}",
+        Field(data.rate_of_turn),
+        Field(data.status.map(DataValidity::value))
+    )
+}
@@ -0,0 +1,14 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_rpm<W: Write>(writer: &mut W, data: &RpmData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{}",
+        Field(data.source.map(RpmSource::value)),
+        Field(data.number),
+        Field(data.rpm),
+        Field(data.pitch),
+        Field(data.status.map(DataValidity::value))
+    )
+}
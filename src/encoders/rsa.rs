@@ -0,0 +1,13 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_rsa<W: Write>(writer: &mut W, data: &RsaData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{}",
+        Field(data.starboard_angle),
+        Field(data.starboard_status.map(DataValidity::value)),
+        Field(data.port_angle),
+        Field(data.port_status.map(DataValidity::value))
+    )
+}
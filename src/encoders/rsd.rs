@@ -0,0 +1,25 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_rsd<W: Write>(writer: &mut W, data: &RsdData) -> fmt::Result {
+    for origin in data.origins.iter() {
+        write!(
+            writer,
+            "{},{},{},{},",
+            Field(origin.range),
+            Field(origin.bearing),
+            Field(origin.variable_range_marker),
+            Field(origin.bearing_line)
+        )?;
+    }
+
+    write!(
+        writer,
+        "{},{},{},{},{}",
+        Field(data.cursor_range),
+        Field(data.cursor_bearing),
+        Field(data.range_scale),
+        Field(data.range_unit.map(DistanceUnit::value)),
+        Field(data.display_rotation.map(DisplayRotation::value))
+    )
+}
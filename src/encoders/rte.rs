@@ -0,0 +1,19 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_rte<W: Write>(writer: &mut W, data: &RteData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{}",
+        Field(data.number_of_sentences),
+        Field(data.sentence_num),
+        Field(data.mode.map(RteMode::value)),
+        Text(data.route_id)
+    )?;
+
+    for waypoint in iter_waypoints(&data.waypoints) {
+        write!(writer, ",{}", Text(waypoint))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_sfi<W: Write>(writer: &mut W, data: &SfiData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{}",
+        Field(data.number_of_sentences),
+        Field(data.sentence_num)
+    )?;
+
+    // Empty pairs after the last frequency are left out instead of padded
+    let count = data
+        .frequencies
+        .iter()
+        .rposition(Option::is_some)
+        .map_or(0, |index| index + 1);
+    for frequency in data.frequencies[..count].iter() {
+        write!(
+            writer,
+            ",{},{}",
+            Frequency(frequency.map(|frequency| frequency.frequency)),
+            Field(
+                frequency
+                    .and_then(|frequency| frequency.mode)
+                    .map(FrequencyMode::value)
+            )
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,6 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_stn<W: Write>(writer: &mut W, data: &StnData) -> fmt::Result {
+    write!(writer, "{:02}", data.talker_id)
+}
@@ -0,0 +1,19 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_tll<W: Write>(writer: &mut W, data: &TllData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{},{}",
+        Field(data.target_number),
+        Position(data.position),
+        Text(data.target_name),
+        Time(data.time),
+        Field(data.status.map(TargetStatus::value)),
+        Field(if data.reference_target {
+            Some('R')
+        } else {
+            None
+        })
+    )
+}
@@ -0,0 +1,37 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_ttm<W: Write>(writer: &mut W, data: &TtmData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{},{},{},{},{},{},{},{},{}",
+        Field(data.target_number),
+        Field(data.distance),
+        Field(data.bearing),
+        Field(data.bearing_reference.map(TargetReference::value)),
+        Field(data.speed),
+        Field(data.course),
+        Field(data.course_reference.map(TargetReference::value)),
+        Field(data.cpa_distance),
+        Field(data.tcpa),
+        Field(data.unit.map(DistanceUnit::value)),
+        Text(data.target_name),
+        Field(data.status.map(TargetStatus::value)),
+        Field(if data.reference_target {
+            Some('R')
+        } else {
+            None
+        })
+    )?;
+
+    if data.time.is_some() || data.acquisition.is_some() {
+        write!(
+            writer,
+            ",{},{}",
+            Time(data.time),
+            Field(data.acquisition.map(TargetAcquisition::value))
+        )?;
+    }
+
+    Ok(())
+}
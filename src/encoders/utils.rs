@@ -0,0 +1,168 @@
+use crate::parse::*;
+use crate::sentences::update_checksum;
+pub(crate) use core::fmt::{self, Display, Formatter, Write};
+
+/// Writes the contained value or leaves the field empty
+pub(crate) struct Field<T>(pub(crate) Option<T>);
+
+impl<T: Display> Display for Field<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.0 {
+            Some(value) => value.fmt(f),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Writes a raw text field such as a waypoint name
+pub(crate) struct Text<'a>(pub(crate) Option<&'a [u8]>);
+
+impl Display for Text<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0 {
+            Some(text) => f.write_str(core::str::from_utf8(text).map_err(|_| fmt::Error)?),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Writes a UTC timestamp as hhmmss.ss
+pub(crate) struct Time(pub(crate) Option<GpsTime>);
+
+impl Display for Time {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0 {
+            Some(time) => {
+                // Rounding could turn 59.996 into 60.00, so the seconds are truncated
+                // to hundredths. The small offset absorbs the error of the f32.
+                let hundredths = (f64::from(time.second) * 100.0 + 1e-3) as u32;
+                write!(
+                    f,
+                    "{:02}{:02}{:02}.{:02}",
+                    time.hour,
+                    time.minute,
+                    hundredths / 100,
+                    hundredths % 100
+                )
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// Writes a date as ddmmyy
+pub(crate) struct Date(pub(crate) Option<GpsDate>);
+
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0 {
+            Some(date) => write!(f, "{:02}{:02}{:02}", date.day, date.month, date.year % 100),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Writes a position as ddmm.mmmm,N,dddmm.mmmm,E
+pub(crate) struct Position(pub(crate) Option<GpsPosition>);
+
+impl Display for Position {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0 {
+            Some(position) => {
+                write_coordinate(f, position.lat, 2)?;
+                write!(f, ",{},", position.lat_dir.value())?;
+                write_coordinate(f, position.lon, 3)?;
+                write!(f, ",{}", position.lon_dir.value())
+            }
+            None => f.write_str(",,,"),
+        }
+    }
+}
+
+/// Splits a coordinate in decimal degrees into degrees and minutes with four
+/// decimal places, the rounding is done on the integer representation so the
+/// minutes can never end up as 60
+fn write_coordinate(f: &mut Formatter, coordinate: f32, degree_digits: usize) -> fmt::Result {
+    let coordinate = if coordinate < 0. {
+        -coordinate
+    } else {
+        coordinate
+    };
+    let ten_thousandth_minutes = (f64::from(coordinate) * 600_000. + 0.5) as u64;
+    let degrees = ten_thousandth_minutes / 600_000;
+    let minutes = ten_thousandth_minutes % 600_000;
+
+    write!(
+        f,
+        "{:0width$}{:02}.{:04}",
+        degrees,
+        minutes / 10_000,
+        minutes % 10_000,
+        width = degree_digits
+    )
+}
+
+/// Wraps a writer and calculates the checksum of everything written through it
+pub(crate) struct ChecksumWriter<'w, W: Write> {
+    writer: &'w mut W,
+    pub(crate) checksum: u8,
+}
+
+impl<'w, W: Write> ChecksumWriter<'w, W> {
+    pub(crate) fn new(writer: &'w mut W) -> Self {
+        ChecksumWriter {
+            writer,
+            checksum: 0,
+        }
+    }
+}
+
+impl<W: Write> Write for ChecksumWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.checksum = update_checksum(self.checksum, s.as_bytes());
+        self.writer.write_str(s)
+    }
+}
+
+/// A writer that fills a byte slice and remembers whether it ran out of space
+pub(crate) struct SliceWriter<'b> {
+    buffer: &'b mut [u8],
+    pub(crate) position: usize,
+    pub(crate) overflow: bool,
+}
+
+impl<'b> SliceWriter<'b> {
+    pub(crate) fn new(buffer: &'b mut [u8]) -> Self {
+        SliceWriter {
+            buffer,
+            position: 0,
+            overflow: false,
+        }
+    }
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.position + s.len();
+        if end > self.buffer.len() {
+            self.overflow = true;
+            return Err(fmt::Error);
+        }
+
+        self.buffer[self.position..end].copy_from_slice(s.as_bytes());
+        self.position = end;
+        Ok(())
+    }
+}
+
+/// Writes a radio frequency in units of 100 Hz with the six digits of FSI and SFI
+pub(crate) struct Frequency(pub(crate) Option<u32>);
+
+impl Display for Frequency {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0 {
+            Some(frequency) => write!(f, "{:06}", frequency),
+            None => Ok(()),
+        }
+    }
+}
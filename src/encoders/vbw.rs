@@ -0,0 +1,15 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_vbw<W: Write>(writer: &mut W, data: &VbwData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{},{}",
+        Field(data.lon_water_speed),
+        Field(data.transverse_water_speed),
+        Field(data.water_validity.map(DataValidity::value)),
+        Field(data.lon_ground_speed),
+        Field(data.transverse_ground_speed),
+        Field(data.ground_validity.map(DataValidity::value))
+    )
+}
@@ -0,0 +1,15 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_vdm<W: Write>(writer: &mut W, data: &VdmData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{},{}",
+        Field(data.fragment_count),
+        Field(data.fragment_number),
+        Field(data.message_id),
+        Field(data.channel.map(AisChannel::value)),
+        Text(Some(data.payload)),
+        Field(data.fill_bits)
+    )
+}
@@ -0,0 +1,12 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_vdr<W: Write>(writer: &mut W, data: &VdrData) -> fmt::Result {
+    write!(
+        writer,
+        "{},T,{},M,{},N",
+        Field(data.set_true),
+        Field(data.set_magnetic),
+        Field(data.drift_knots)
+    )
+}
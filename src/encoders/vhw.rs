@@ -0,0 +1,13 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_vhw<W: Write>(writer: &mut W, data: &VhwData) -> fmt::Result {
+    write!(
+        writer,
+        "{},T,{},M,{},N,{},K",
+        Field(data.heading_true),
+        Field(data.heading_magnetic),
+        Field(data.speed_knots),
+        Field(data.speed_kmh)
+    )
+}
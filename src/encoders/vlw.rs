@@ -0,0 +1,22 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_vlw<W: Write>(writer: &mut W, data: &VlwData) -> fmt::Result {
+    write!(
+        writer,
+        "{},N,{},N",
+        Field(data.total_water_distance),
+        Field(data.trip_water_distance)
+    )?;
+
+    if data.total_ground_distance.is_some() || data.trip_ground_distance.is_some() {
+        write!(
+            writer,
+            ",{},N,{},N",
+            Field(data.total_ground_distance),
+            Field(data.trip_ground_distance)
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,11 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_vpw<W: Write>(writer: &mut W, data: &VpwData) -> fmt::Result {
+    write!(
+        writer,
+        "{},N,{},M",
+        Field(data.speed_knots),
+        Field(data.speed_mps)
+    )
+}
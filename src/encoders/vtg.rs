@@ -0,0 +1,13 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_vtg<W: Write>(writer: &mut W, data: &VtgData) -> fmt::Result {
+    write!(
+        writer,
+        "{},T,{},M,{},N,{},K",
+        Field(data.bearing_true),
+        Field(data.bearing_magnetic),
+        Field(data.speed_knots),
+        Field(data.speed_kmh)
+    )
+}
@@ -0,0 +1,14 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_vwr<W: Write>(writer: &mut W, data: &VwrData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},N,{},M,{},K",
+        Field(data.angle),
+        Field(data.side.map(WindSide::value)),
+        Field(data.speed_knots),
+        Field(data.speed_mps),
+        Field(data.speed_kmh)
+    )
+}
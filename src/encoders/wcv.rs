@@ -0,0 +1,17 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_wcv<W: Write>(writer: &mut W, data: &WcvData) -> fmt::Result {
+    write!(
+        writer,
+        "{},N,{}",
+        Field(data.velocity_knots),
+        Text(data.waypoint)
+    )?;
+
+    if let Some(mode) = data.mode {
+        write!(writer, ",{}", mode.value())?;
+    }
+
+    Ok(())
+}
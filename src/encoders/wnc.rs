@@ -0,0 +1,13 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_wnc<W: Write>(writer: &mut W, data: &WncData) -> fmt::Result {
+    write!(
+        writer,
+        "{},N,{},K,{},{}",
+        Field(data.nautical_miles),
+        Field(data.kilometers),
+        Text(data.to_waypoint),
+        Text(data.from_waypoint)
+    )
+}
@@ -0,0 +1,11 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_wpl<W: Write>(writer: &mut W, data: &WplData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{}",
+        Position(data.position),
+        Text(data.waypoint_name)
+    )
+}
@@ -0,0 +1,21 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_xdr<W: Write>(writer: &mut W, data: &XdrData) -> fmt::Result {
+    for (index, measurement) in data.measurements().enumerate() {
+        if index > 0 {
+            writer.write_char(',')?;
+        }
+
+        write!(
+            writer,
+            "{},{},{},{}",
+            Field(measurement.transducer.map(XdrTransducer::value)),
+            Field(measurement.value),
+            Field(measurement.unit.map(XdrUnit::value)),
+            Text(measurement.name)
+        )?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_xte<W: Write>(writer: &mut W, data: &XteData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{},{},{}",
+        Field(data.status.map(DataValidity::value)),
+        Field(data.cycle_lock_status.map(DataValidity::value)),
+        Field(data.cross_track_error),
+        Field(data.steer_direction.map(SteerDirection::value)),
+        Field(data.cross_track_unit.map(DistanceUnit::value))
+    )?;
+
+    if let Some(mode) = data.mode {
+        write!(writer, ",{}", mode.value())?;
+    }
+
+    Ok(())
+}
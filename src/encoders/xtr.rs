@@ -0,0 +1,12 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_xtr<W: Write>(writer: &mut W, data: &XtrData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{}",
+        Field(data.cross_track_error),
+        Field(data.steer_direction.map(SteerDirection::value)),
+        Field(data.cross_track_unit.map(DistanceUnit::value))
+    )
+}
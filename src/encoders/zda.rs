@@ -0,0 +1,34 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_zda<W: Write>(writer: &mut W, data: &ZdaData) -> fmt::Result {
+    write!(writer, "{},", Time(data.time))?;
+
+    match data.date {
+        Some(date) => write!(
+            writer,
+            "{:02},{:02},{:04},",
+            date.day, date.month, date.year
+        )?,
+        None => writer.write_str(",,,")?,
+    }
+
+    if let Some(zone) = data.local_zone {
+        let sign = if zone.hours < 0 || zone.minutes < 0 {
+            "-"
+        } else {
+            ""
+        };
+        write!(
+            writer,
+            "{}{:02},{:02}",
+            sign,
+            zone.hours.unsigned_abs(),
+            zone.minutes.unsigned_abs()
+        )?;
+    } else {
+        writer.write_char(',')?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,12 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_zfo<W: Write>(writer: &mut W, data: &ZfoData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{}",
+        Time(data.time),
+        Time(data.elapsed_time),
+        Text(data.origin_waypoint)
+    )
+}
@@ -0,0 +1,12 @@
+use super::utils::*;
+use crate::parse::*;
+
+pub(crate) fn encode_ztg<W: Write>(writer: &mut W, data: &ZtgData) -> fmt::Result {
+    write!(
+        writer,
+        "{},{},{}",
+        Time(data.time),
+        Time(data.time_to_go),
+        Text(data.destination_waypoint)
+    )
+}
@@ -4,7 +4,8 @@ use crate::sentences::SentenceType;
 /// a GeneralSentence
 #[derive(Debug, Clone, PartialEq)]
 pub enum NmeaSentenceError<'a> {
-    /// Is thrown if the sentence was longer than 102 characters.
+    /// Is thrown if the sentence was longer than MAX_SENTENCE_LENGTH characters,
+    /// or than MAX_PROPRIETARY_SENTENCE_LENGTH for proprietary ones.
     /// The integer associated with this is the length of the sentence
     SentenceLengthError(usize),
     /// Is thrown if the chekcsum of the parsed and the calculated one do not match up.
@@ -26,4 +27,73 @@ pub enum NmeaSentenceError<'a> {
     /// Is thrown if one of the status enums defined inside parse.rs is not able
     /// to be created based on the given input
     StatusParsingError(StatusParsingError),
+    /// Is thrown by the NmeaDecoder if a sentence was interrupted by the start
+    /// of a new one or contained bytes that are not allowed inside of a sentence
+    FramingError,
+}
+
+/// A list of errors that can occur while encoding a sentence
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NmeaEncodeError {
+    /// Is thrown if no encoder exists for a given NMEA sentence type yet,
+    /// contains the sentence type for which no encoder was found
+    TypeNotImplementedError(SentenceType),
+    /// Is thrown if the talker is proprietary or its id is not made up
+    /// of two uppercase ASCII characters
+    TalkerError,
+    /// Is thrown if the output buffer is too small to hold the sentence.
+    /// The integer associated with this is the length of the buffer
+    BufferLengthError(usize),
+    /// Is thrown if the underlying writer reported an error
+    FormatError,
+    /// Is thrown if a parameter of a command lies outside of
+    /// the range the receiver accepts
+    RangeError,
+}
+
+impl From<core::fmt::Error> for NmeaEncodeError {
+    fn from(_: core::fmt::Error) -> Self {
+        NmeaEncodeError::FormatError
+    }
+}
+
+/// A list of errors that can occur while assembling a message out of several
+/// sentences, e.g. a SkyView out of a GSV cycle or a Route out of RTE sentences
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AssemblyError {
+    /// Is thrown if a sentence lacks the number of sentences, its own sentence
+    /// number or another field the message can not do without, such as the
+    /// satellite id of an ALM sentence
+    MissingFieldError,
+    /// Is thrown if the sentence number is zero or larger than the
+    /// number of sentences of the message
+    SentenceCountError,
+    /// Is thrown if a sentence does not continue the message in progress,
+    /// contains the expected and the received sentence number
+    SentenceOrderError { expected: u16, received: u16 },
+    /// Is thrown if the message, one of its parts or the amount of messages
+    /// in progress exceeds the fixed capacity of the assembler
+    CapacityError,
+    /// Is thrown if a field holds a value the message can not be assembled
+    /// with, such as an ALM satellite id outside of 1 to 32 or an AIS payload
+    /// character outside of the armoring
+    InvalidDataError,
+}
+
+/// A list of errors that can occur while decoding an AIS payload
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AisDecodeError {
+    /// Is thrown if the payload contains a character outside of the AIS
+    /// armoring or the amount of fill bits is invalid
+    ArmoringError,
+    /// Is thrown if the payload exceeds the fixed capacity of an AisPayload
+    CapacityError,
+    /// Is thrown if the payload is too short for its message type,
+    /// contains the length of the payload in bits
+    LengthError(usize),
+    /// Is thrown if a field holds a value that is reserved by the standard
+    FieldError,
+    /// Is thrown if no decoder exists for a given message type yet,
+    /// contains the message type for which no decoder was found
+    TypeNotImplementedError(u8),
 }
@@ -1,21 +1,51 @@
 #![deny(warnings)]
+#![allow(clippy::type_complexity)]
 #![no_std]
 
 #[macro_use]
 extern crate nom;
 use errors::NmeaSentenceError;
-use parse::{parse_sentence_data, SentenceData};
+use parse::{parse_sentence_data, NmeaSentence, SentenceData};
 use sentences::GeneralSentence;
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
+pub mod ais;
+pub mod almanac;
+mod assembly;
+pub mod decoder;
+pub mod encode;
+mod encoders;
 pub mod errors;
+pub mod nav_state;
 pub mod parse;
 mod parsers;
+pub mod pmtk;
+pub mod proprietary;
+pub mod route;
 pub mod sentences;
+pub mod sky_view;
 
 /// The central entrypoint for the library, it verifies and parses a given sentence
 /// into a specific data struct for the user
-pub fn parse_nmea_sentence(sentence: &[u8]) -> Result<SentenceData, NmeaSentenceError> {
+///
+/// Proprietary sentences are only parsed with `BUILTIN_PROPRIETARY_PARSERS`,
+/// the ones they do not know are returned as `Proprietary::Raw` and can be
+/// parsed with your own parsers through `ProprietarySentence::parse_with`.
+pub fn parse_nmea_sentence(sentence: &[u8]) -> Result<SentenceData<'_>, NmeaSentenceError<'_>> {
     parse_sentence_data(GeneralSentence::new(sentence)?)
 }
+
+/// Verifies and parses a given sentence just like parse_nmea_sentence but
+/// keeps the talker and the raw sentence around as well
+pub fn parse_nmea_sentence_with_talker(
+    sentence: &[u8],
+) -> Result<NmeaSentence<'_>, NmeaSentenceError<'_>> {
+    let general_sentence = GeneralSentence::new(sentence)?;
+    Ok(NmeaSentence {
+        talker: general_sentence.talker,
+        sentence_type: general_sentence.sentence_type,
+        raw: general_sentence,
+        data: parse_sentence_data(general_sentence)?,
+    })
+}
@@ -0,0 +1,358 @@
+use crate::parse::*;
+
+/// A value together with the epoch it was last updated in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tracked<T> {
+    pub value: T,
+    pub epoch: u32,
+}
+
+/// The current navigation solution, merged from all sentences that describe a fix.
+///
+/// Every sentence carrying a UTC timestamp that differs from the one of the current
+/// epoch starts a new epoch, sentences without a timestamp such as GSA and VTG are
+/// assigned to the current one. Each field remembers the epoch it was last updated in
+/// so stale values can be told apart from fresh ones.
+///
+/// With datum tagging enabled the local datum announced by DTM sentences is recorded
+/// and every position is tagged with the datum that was in effect when it was received.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct NavState {
+    pub epoch: u32,
+    pub time: Option<Tracked<GpsTime>>,
+    pub date: Option<Tracked<GpsDate>>,
+    pub position: Option<Tracked<GpsPosition>>,
+    pub altitude: Option<Tracked<f32>>,
+    pub geoid_separation: Option<Tracked<f32>>,
+    pub speed_knots: Option<Tracked<f32>>,
+    pub course_true: Option<Tracked<f32>>,
+    pub course_magnetic: Option<Tracked<f32>>,
+    pub pdop: Option<Tracked<f32>>,
+    pub hdop: Option<Tracked<f32>>,
+    pub vdop: Option<Tracked<f32>>,
+    pub quality: Option<Tracked<GpsQuality>>,
+    pub fix_mode: Option<Tracked<GsaMode>>,
+    pub sats_used: Option<Tracked<u8>>,
+    pub datum: Option<Tracked<Datum>>,
+    pub position_datum: Option<Datum>,
+    sats_used_from_gsa: bool,
+    datum_tagging: bool,
+}
+
+impl NavState {
+    /// Generates a new NavState without any information about the fix
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Generates a new NavState that tags every position with its datum
+    pub fn with_datum_tagging() -> Self {
+        NavState {
+            datum_tagging: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the value of a field if it was updated during the current epoch
+    pub fn current<T: Copy>(&self, field: Option<Tracked<T>>) -> Option<T> {
+        match field {
+            Some(tracked) if tracked.epoch == self.epoch => Some(tracked.value),
+            _ => None,
+        }
+    }
+
+    /// Merges the data of a sentence into the navigation solution. Sentences that
+    /// do not describe the fix are ignored. Returns true if the sentence started a
+    /// new epoch.
+    pub fn update(&mut self, data: &SentenceData) -> bool {
+        match data {
+            SentenceData::GGA(gga) => {
+                let new_epoch = self.update_time(gga.time);
+                if gga.quality != Some(GpsQuality::FixNotAvailable) {
+                    self.set_position(gga.position);
+                }
+                self.set_sats_used(gga.sats_in_view);
+                Self::set(&mut self.quality, gga.quality, self.epoch);
+                Self::set(&mut self.hdop, gga.hdop, self.epoch);
+                Self::set(&mut self.altitude, gga.altitude, self.epoch);
+                Self::set(&mut self.geoid_separation, gga.geoid_altitude, self.epoch);
+                new_epoch
+            }
+            SentenceData::RMC(rmc) => {
+                let new_epoch = self.update_time(rmc.time);
+                if rmc.status != Some(RmStatus::Warning) {
+                    self.set_position(rmc.position);
+                }
+                Self::set(&mut self.date, rmc.date, self.epoch);
+                Self::set(&mut self.speed_knots, rmc.speed, self.epoch);
+                Self::set(&mut self.course_true, rmc.heading, self.epoch);
+                new_epoch
+            }
+            SentenceData::GLL(gll) => {
+                let new_epoch = self.update_time(gll.time);
+                if gll.status != Some(GllStatus::DataInvalid) {
+                    self.set_position(gll.position);
+                }
+                new_epoch
+            }
+            #[cfg(feature = "alloc")]
+            SentenceData::GNS(gns) => {
+                let new_epoch = self.update_time(gns.time);
+                let no_fix = match &gns.mode {
+                    Some(modes) => modes.iter().all(|mode| *mode == GnsMode::NoFix),
+                    None => false,
+                };
+                if !no_fix {
+                    self.set_position(gns.position);
+                }
+                self.set_sats_used(gns.sats_in_use);
+                Self::set(&mut self.hdop, gns.hdop, self.epoch);
+                Self::set(&mut self.altitude, gns.orthometric_height, self.epoch);
+                Self::set(&mut self.geoid_separation, gns.geoid_seperation, self.epoch);
+                new_epoch
+            }
+            SentenceData::GSA(gsa) => {
+                let used = gsa.satellites.iter().flatten().count() as u8;
+                match self.sats_used {
+                    // GGA and GNS state the amount of satellites used directly
+                    Some(tracked) if tracked.epoch == self.epoch && !self.sats_used_from_gsa => {}
+                    // Multi constellation receivers send one GSA per constellation
+                    // and epoch, their satellites add up to the ones used in the fix
+                    Some(tracked) if tracked.epoch == self.epoch => {
+                        Self::set(
+                            &mut self.sats_used,
+                            Some(tracked.value.saturating_add(used)),
+                            self.epoch,
+                        );
+                    }
+                    _ => {
+                        Self::set(&mut self.sats_used, Some(used), self.epoch);
+                        self.sats_used_from_gsa = true;
+                    }
+                }
+                Self::set(&mut self.fix_mode, gsa.mode, self.epoch);
+                Self::set(&mut self.pdop, gsa.pdob, self.epoch);
+                Self::set(&mut self.hdop, gsa.hdop, self.epoch);
+                Self::set(&mut self.vdop, gsa.vdop, self.epoch);
+                false
+            }
+            SentenceData::VTG(vtg) => {
+                let speed_knots = match (vtg.speed_knots, vtg.speed_kmh) {
+                    (Some(knots), _) => Some(knots),
+                    (None, Some(kmh)) => Some(kmh / 1.852),
+                    (None, None) => None,
+                };
+                Self::set(&mut self.speed_knots, speed_knots, self.epoch);
+                Self::set(&mut self.course_true, vtg.bearing_true, self.epoch);
+                Self::set(&mut self.course_magnetic, vtg.bearing_magnetic, self.epoch);
+                false
+            }
+            SentenceData::DTM(dtm) if self.datum_tagging => {
+                Self::set(&mut self.datum, dtm.local_datum, self.epoch);
+                false
+            }
+            _ => false,
+        }
+    }
+
+    /// Starts a new epoch if the timestamp differs from the one of the current epoch
+    fn update_time(&mut self, time: Option<GpsTime>) -> bool {
+        let time = match time {
+            Some(time) => time,
+            None => return false,
+        };
+
+        let new_epoch = match self.time {
+            Some(tracked) => tracked.value != time,
+            None => true,
+        };
+        if new_epoch {
+            self.epoch = self.epoch.wrapping_add(1);
+        }
+
+        Self::set(&mut self.time, Some(time), self.epoch);
+        new_epoch
+    }
+
+    fn set_position(&mut self, position: GpsPosition) {
+        Self::set(&mut self.position, Some(position), self.epoch);
+        if self.datum_tagging {
+            self.position_datum = self.datum.map(|datum| datum.value);
+        }
+    }
+
+    fn set_sats_used(&mut self, sats_used: Option<u8>) {
+        if sats_used.is_some() {
+            self.sats_used_from_gsa = false;
+        }
+        Self::set(&mut self.sats_used, sats_used, self.epoch);
+    }
+
+    fn set<T>(field: &mut Option<Tracked<T>>, value: Option<T>, epoch: u32) {
+        if let Some(value) = value {
+            *field = Some(Tracked { value, epoch });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_nmea_sentence;
+
+    fn update(state: &mut NavState, sentence: &[u8]) -> bool {
+        state.update(&parse_nmea_sentence(sentence).unwrap())
+    }
+
+    fn position_of(sentence: &[u8]) -> GpsPosition {
+        match parse_nmea_sentence(sentence).unwrap() {
+            SentenceData::GGA(gga) => gga.position,
+            SentenceData::RMC(rmc) => rmc.position,
+            data => panic!("unexpected data {:?}", data),
+        }
+    }
+
+    #[test]
+    fn merges_the_sentences_of_an_epoch() {
+        let mut state = NavState::new();
+        let rmc: &[u8] = b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,*2E\r\n";
+        assert!(update(&mut state, rmc));
+        assert!(!update(
+            &mut state,
+            b"$GNGSA,A,3,04,05,09,12,,,,,,,,,2.5,1.3,2.1*21\r\n"
+        ));
+        assert!(!update(
+            &mut state,
+            b"$GNGSA,A,3,65,66,67,,,,,,,,,,2.5,1.3,2.1*28\r\n"
+        ));
+
+        assert_eq!(state.epoch, 1);
+        assert_eq!(
+            state.current(state.time),
+            Some(GpsTime {
+                hour: 9,
+                minute: 27,
+                second: 50.0,
+            })
+        );
+        assert_eq!(
+            state.current(state.date),
+            Some(GpsDate {
+                day: 28,
+                month: 5,
+                year: 11,
+            })
+        );
+        assert_eq!(state.current(state.position), Some(position_of(rmc)));
+        assert_eq!(state.current(state.speed_knots), Some(0.02));
+        assert_eq!(state.current(state.fix_mode), Some(GsaMode::Fix3D));
+        assert_eq!(state.current(state.pdop), Some(2.5));
+        // Both GSA sentences list the satellites of one constellation each
+        assert_eq!(state.current(state.sats_used), Some(7));
+
+        // GGA of the same epoch states the amount of satellites used directly
+        assert!(!update(
+            &mut state,
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,08,1.03,61.7,M,55.2,M,,*46\r\n"
+        ));
+        assert_eq!(state.epoch, 1);
+        assert_eq!(state.current(state.sats_used), Some(8));
+        assert_eq!(state.current(state.altitude), Some(61.7));
+        assert_eq!(state.current(state.hdop), Some(1.03));
+        assert!(!update(
+            &mut state,
+            b"$GNGSA,A,3,04,05,09,12,,,,,,,,,2.5,1.3,2.1*21\r\n"
+        ));
+        assert_eq!(state.current(state.sats_used), Some(8));
+    }
+
+    #[test]
+    fn tracks_the_freshness_of_every_field() {
+        let mut state = NavState::new();
+        update(
+            &mut state,
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,08,1.03,61.7,M,55.2,M,,*46\r\n",
+        );
+        update(
+            &mut state,
+            b"$GPRMC,092750.000,A,5321.6802,N,00630.3372,W,0.02,31.66,280511,,*2E\r\n",
+        );
+
+        let rmc: &[u8] = b"$GPRMC,092751.000,A,5321.6803,N,00630.3373,W,0.50,31.70,280511,,*2F\r\n";
+        assert!(update(&mut state, rmc));
+        assert_eq!(state.epoch, 2);
+        assert_eq!(state.current(state.speed_knots), Some(0.5));
+        assert_eq!(state.current(state.position), Some(position_of(rmc)));
+        // Only GGA carries the altitude, so it is left over from the last epoch
+        assert_eq!(state.current(state.altitude), None);
+        assert_eq!(
+            state.altitude,
+            Some(Tracked {
+                value: 61.7,
+                epoch: 1,
+            })
+        );
+        assert_eq!(state.current(state.sats_used), None);
+
+        // The satellites of the previous epoch's GGA are not added to
+        assert!(!update(
+            &mut state,
+            b"$GNGSA,A,3,04,05,09,12,24,,,,,,,,2.4,1.2,2.0*26\r\n"
+        ));
+        assert_eq!(state.current(state.sats_used), Some(5));
+        assert_eq!(state.current(state.hdop), Some(1.2));
+    }
+
+    #[test]
+    fn keeps_the_position_of_invalid_fixes_out() {
+        let mut state = NavState::new();
+        let rmc: &[u8] = b"$GPRMC,092751.000,A,5321.6803,N,00630.3373,W,0.50,31.70,280511,,*2F\r\n";
+        update(&mut state, rmc);
+        assert!(update(
+            &mut state,
+            b"$GPGGA,092752.000,5321.6804,N,00630.3374,W,0,00,,,M,,M,,*53\r\n"
+        ));
+        assert!(!update(
+            &mut state,
+            b"$GPRMC,092752.000,V,5321.6804,N,00630.3374,W,,,280511,,*0B\r\n"
+        ));
+
+        assert_eq!(
+            state.current(state.quality),
+            Some(GpsQuality::FixNotAvailable)
+        );
+        assert_eq!(state.current(state.position), None);
+        assert_eq!(
+            state.position,
+            Some(Tracked {
+                value: position_of(rmc),
+                epoch: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn tags_positions_with_their_datum() {
+        let dtm: &[u8] = b"$GPDTM,999,CH,30.0,N,15.0,W,-2.5,W84*39\r\n";
+        let rmc: &[u8] = b"$GPRMC,092750.000,A,1000.0000,N,00007.5000,W,0.02,31.66,280511,,*24\r\n";
+
+        let mut state = NavState::with_datum_tagging();
+        assert_eq!(state.position_datum, None);
+        update(&mut state, rmc);
+        assert_eq!(state.position_datum, None);
+
+        assert!(!update(&mut state, dtm));
+        assert_eq!(state.current(state.datum), Some(Datum::UserDefined));
+        // The position was received before the datum was announced
+        assert_eq!(state.position_datum, None);
+        update(&mut state, rmc);
+        assert_eq!(state.position_datum, Some(Datum::UserDefined));
+
+        // Without datum tagging DTM sentences are ignored
+        let mut state = NavState::new();
+        update(&mut state, dtm);
+        update(&mut state, rmc);
+        assert_eq!(state.datum, None);
+        assert_eq!(state.position_datum, None);
+    }
+}
@@ -1,6 +1,7 @@
 use crate::errors::NmeaSentenceError;
 use crate::parsers;
-use crate::sentences::{GeneralSentence, SentenceType};
+use crate::proprietary::Proprietary;
+use crate::sentences::{GeneralSentence, SentenceType, Talker};
 
 macro_rules! status {
     ($($name:ident, $type:ty : [$($input:tt => $status:ident),+ error: $error:ident]),+) => {
@@ -28,6 +29,15 @@ macro_rules! status {
                         _ => Err(NmeaSentenceError::StatusParsingError(StatusParsingError::$error)),
                     }
                 }
+
+                #[allow(unused)]
+                pub (crate) fn value(self) -> $type {
+                    match self {
+                        $(
+                            $name::$status => $input,
+                        )+
+                    }
+                }
             }
         )+
     }
@@ -89,7 +99,8 @@ status! {
         error: RteModeError
     ],
     DataValidity, char: [
-        'A' => DataValid
+        'A' => DataValid,
+        'V' => DataInvalid
         error: DataValidityError
     ],
     GnsMode, char: [
@@ -103,30 +114,226 @@ status! {
         'M' => Manual,
         'S' => Simulator
         error: GnsModeError
+    ],
+    DepthUnit, char: [
+        'f' => Feet,
+        'M' => Meters,
+        'F' => Fathoms
+        error: DepthUnitError
+    ],
+    WindReference, char: [
+        'R' => Relative,
+        'T' => Theoretical
+        error: WindReferenceError
+    ],
+    WindSide, char: [
+        'L' => Left,
+        'R' => Right
+        error: WindSideError
+    ],
+    SpeedUnit, char: [
+        'K' => KilometersPerHour,
+        'M' => MetersPerSecond,
+        'N' => Knots,
+        'S' => MilesPerHour
+        error: SpeedUnitError
+    ],
+    GrsMode, u8: [
+        0 => UsedInPosition,
+        1 => RecomputedAfterPosition
+        error: GrsModeError
+    ],
+    RpmSource, char: [
+        'S' => Shaft,
+        'E' => Engine
+        error: RpmSourceError
+    ],
+    TemperatureUnit, char: [
+        'C' => Celsius
+        error: TemperatureUnitError
+    ],
+    LoranStatus, char: [
+        'A' => Valid,
+        'B' => BlinkWarning,
+        'C' => CycleWarning,
+        'S' => SnrWarning
+        error: LoranStatusError
+    ],
+    TargetReference, char: [
+        'T' => True,
+        'R' => Relative
+        error: TargetReferenceError
+    ],
+    TargetStatus, char: [
+        'L' => Lost,
+        'Q' => Acquiring,
+        'T' => Tracking
+        error: TargetStatusError
+    ],
+    TargetAcquisition, char: [
+        'A' => Automatic,
+        'M' => Manual,
+        'R' => Reported
+        error: TargetAcquisitionError
+    ],
+    AisChannel, char: [
+        'A' => A,
+        'B' => B
+        error: AisChannelError
+    ],
+    AisNavigationStatus, u8: [
+        0 => UnderWayUsingEngine,
+        1 => AtAnchor,
+        2 => NotUnderCommand,
+        3 => RestrictedManoeuverability,
+        4 => ConstrainedByDraught,
+        5 => Moored,
+        6 => Aground,
+        7 => EngagedInFishing,
+        8 => UnderWaySailing,
+        9 => ReservedHighSpeedCraft,
+        10 => ReservedWingInGround,
+        11 => TowingAstern,
+        12 => PushingAhead,
+        13 => Reserved,
+        14 => AisSartActive
+        error: AisNavigationStatusError
+    ],
+    PubxSatelliteStatus, char: [
+        '-' => NotUsed,
+        'U' => Used,
+        'e' => EphemerisAvailable
+        error: PubxSatelliteStatusError
+    ],
+    PmtkAckFlag, char: [
+        '0' => InvalidCommand,
+        '1' => UnsupportedCommand,
+        '2' => ActionFailed,
+        '3' => ActionSucceeded
+        error: PmtkAckFlagError
+    ],
+    PgrmzAltitudeSource, char: [
+        '2' => UserAltitude,
+        '3' => GpsAltitude
+        error: PgrmzAltitudeSourceError
+    ],
+    AbkAcknowledgement, u8: [
+        0 => Received,
+        1 => NotAcknowledged,
+        2 => NotBroadcast,
+        3 => BroadcastCompleted,
+        4 => LateAcknowledgement
+        error: AbkAcknowledgementError
+    ],
+    DeccaFixBasis, u8: [
+        1 => NormalPattern,
+        2 => LaneIdentificationPattern,
+        3 => LaneIdentificationTransmissions
+        error: DeccaFixBasisError
+    ],
+    FrequencyMode, char: [
+        'd' => SimplexTelephone,
+        'e' => DuplexTelephone,
+        'm' => SsbTelephone,
+        'o' => AmTelephone,
+        'q' => FecTelex,
+        's' => ArqTelex,
+        't' => ReceiveOnlyTeleprinter,
+        'w' => Teleprinter,
+        'x' => MorseTapeRecorder,
+        '{' => MorseKey,
+        '|' => Facsimile
+        error: FrequencyModeError
+    ],
+    DisplayRotation, char: [
+        'C' => CourseUp,
+        'H' => HeadUp,
+        'N' => NorthUp
+        error: DisplayRotationError
+    ],
+    MotionReference, char: [
+        'B' => BottomTracking,
+        'M' => Manual,
+        'W' => WaterReferenced,
+        'R' => RadarTracking,
+        'P' => PositioningSystem
+        error: MotionReferenceError
+    ],
+    DistanceUnit, char: [
+        'N' => NauticalMiles,
+        'K' => Kilometers,
+        'S' => StatuteMiles
+        error: DistanceUnitError
+    ],
+    BearingReference, char: [
+        'M' => Magnetic,
+        'T' => True
+        error: BearingReferenceError
     ]
 }
 
-/// An enum storing consisting of all NMEA sentence types
-/// together with their corresponding data structs
-#[derive(Debug, Clone, PartialEq)]
-pub enum SentenceData<'a> {
-    AAM(AamData),
+impl SpeedUnit {
+    fn meters_per_second(self) -> f32 {
+        match self {
+            SpeedUnit::KilometersPerHour => 1. / 3.6,
+            SpeedUnit::MetersPerSecond => 1.,
+            SpeedUnit::Knots => 1852. / 3600.,
+            SpeedUnit::MilesPerHour => 1609.344 / 3600.,
+        }
+    }
+
+    /// Converts a speed given in this unit into another unit
+    pub fn convert(self, speed: f32, unit: SpeedUnit) -> f32 {
+        if self == unit {
+            return speed;
+        }
+
+        speed * self.meters_per_second() / unit.meters_per_second()
+    }
+}
+
+macro_rules! sentence_data {
+    ($($TYPE:ident($data:ty)),+) => {
+        /// An enum storing consisting of all NMEA sentence types
+        /// together with their corresponding data structs
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum SentenceData<'a> {
+            $(
+                $TYPE($data),
+            )+
+        }
+
+        impl<'a> SentenceData<'a> {
+            /// Returns the type of the sentence the data belongs to
+            pub fn sentence_type(&self) -> SentenceType {
+                match self {
+                    $(
+                        SentenceData::$TYPE(_) => SentenceType::$TYPE,
+                    )+
+                }
+            }
+        }
+    }
+}
+
+sentence_data!(
+    AAM(AamData<'a>),
     ABK(AbkData),
     ACK(AckData),
     ALM(AlmData),
-    APA(ApaData),
-    APB(ApbData),
-    BEC(BecData),
+    APA(ApaData<'a>),
+    APB(ApbData<'a>),
+    BEC(BecData<'a>),
     BOD(BodData<'a>),
     BWC(BwcData<'a>),
-    BWR(BwrData),
-    BWW(BwwData),
+    BWR(BwrData<'a>),
+    BWW(BwwData<'a>),
     DBK(DbkData),
     DBS(DbsData),
     DBT(DbtData),
-    DCN(DcnData),
+    DCN(DcnData<'a>),
     DPT(DptData),
-    DTM(DtmData),
+    DTM(DtmData<'a>),
     FSI(FsiData),
     GBS(GbsData),
     GGA(GgaData),
@@ -138,18 +345,20 @@ pub enum SentenceData<'a> {
     GSA(GsaData),
     GSV(GsvData),
     GTD(GtdData),
-    GXA(GxaData),
+    GXA(GxaData<'a>),
     HDG(HdgData),
     HDM(HdmData),
     HDT(HdtData),
     HSC(HscData),
     LCD(LcdData),
+    MDA(MdaData),
     MSK(MskData),
     MTW(MtwData),
+    MWD(MwdData),
     MWV(MwvData),
-    OLN(OlnData),
+    OLN(OlnData<'a>),
     OSD(OsdData),
-    ROO(RooData),
+    ROO(RooData<'a>),
     RMA(RmaData),
     RMB(RmbData<'a>),
     RMC(RmcData),
@@ -157,27 +366,40 @@ pub enum SentenceData<'a> {
     RPM(RpmData),
     RSA(RsaData),
     RSD(RsdData),
-    RTE(RteData),
+    RTE(RteData<'a>),
     SFI(SfiData),
     STN(StnData),
-    TLL(TllData),
-    TTM(TtmData),
+    TLL(TllData<'a>),
+    TTM(TtmData<'a>),
     VBW(VbwData),
+    VDM(VdmData<'a>),
+    VDO(VdoData<'a>),
     VDR(VdrData),
     VHW(VhwData),
     VLW(VlwData),
     VPW(VpwData),
     VTG(VtgData),
     VWR(VwrData),
-    WCV(WcvData),
-    WNC(WncData),
+    WCV(WcvData<'a>),
+    WNC(WncData<'a>),
     WPL(WplData<'a>),
-    XDR(XdrData),
+    XDR(XdrData<'a>),
     XTE(XteData),
     XTR(XtrData),
     ZDA(ZdaData),
-    ZFO(ZfoData),
-    ZTG(ZtgData),
+    ZFO(ZfoData<'a>),
+    ZTG(ZtgData<'a>),
+    Proprietary(Proprietary<'a>)
+);
+
+/// A parsed sentence together with the talker it originates from
+/// and the verified raw sentence it was parsed from
+#[derive(Debug, Clone, PartialEq)]
+pub struct NmeaSentence<'a> {
+    pub talker: Talker,
+    pub sentence_type: SentenceType,
+    pub raw: GeneralSentence<'a>,
+    pub data: SentenceData<'a>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -197,6 +419,22 @@ pub struct GpsPosition {
     pub lon_dir: LongitudeDirection,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Represents a Loran-C time difference or time of arrival in microseconds,
+/// only GLC reports the status of the signal it was measured on
+pub struct LoranTd {
+    pub microseconds: f32,
+    pub status: Option<LoranStatus>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Represents the quality of a received Loran-C signal
+pub struct LoranSignal {
+    pub snr: Option<f32>,
+    /// The envelope to cycle discrepancy in microseconds
+    pub ecd: Option<f32>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 /// Represents the current date
 pub struct GpsDate {
@@ -205,6 +443,331 @@ pub struct GpsDate {
     pub year: u64,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Represents the code of a geodetic datum
+pub enum Datum {
+    Wgs84,
+    Wgs72,
+    Sgs85,
+    Pe90,
+    UserDefined,
+    /// Any other IHO datum code
+    Other([u8; 3]),
+}
+
+impl Datum {
+    pub(crate) fn try_from(code: &[u8]) -> Result<Self, NmeaSentenceError<'_>> {
+        match code {
+            b"W84" => Ok(Datum::Wgs84),
+            b"W72" => Ok(Datum::Wgs72),
+            b"S85" => Ok(Datum::Sgs85),
+            b"P90" => Ok(Datum::Pe90),
+            b"999" => Ok(Datum::UserDefined),
+            [first, second, third] => Ok(Datum::Other([*first, *second, *third])),
+            _ => Err(NmeaSentenceError::GeneralParsingError),
+        }
+    }
+
+    /// Returns the datum code as it is used inside of sentences
+    pub fn as_bytes(&self) -> &[u8] {
+        match self {
+            Datum::Wgs84 => b"W84",
+            Datum::Wgs72 => b"W72",
+            Datum::Sgs85 => b"S85",
+            Datum::Pe90 => b"P90",
+            Datum::UserDefined => b"999",
+            Datum::Other(code) => code,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Represents the kind of quantity an XDR transducer measures
+pub enum XdrTransducer {
+    Angular,
+    Temperature,
+    LinearDisplacement,
+    Frequency,
+    Force,
+    Pressure,
+    FlowRate,
+    Tachometer,
+    Humidity,
+    Volume,
+    Generic,
+    Current,
+    Voltage,
+    Switch,
+    Salinity,
+    /// Any other, usually manufacturer specific, transducer type
+    Other(char),
+}
+
+impl XdrTransducer {
+    pub(crate) fn from_char(transducer: char) -> Self {
+        match transducer {
+            'A' => XdrTransducer::Angular,
+            'C' => XdrTransducer::Temperature,
+            'D' => XdrTransducer::LinearDisplacement,
+            'F' => XdrTransducer::Frequency,
+            'N' => XdrTransducer::Force,
+            'P' => XdrTransducer::Pressure,
+            'R' => XdrTransducer::FlowRate,
+            'T' => XdrTransducer::Tachometer,
+            'H' => XdrTransducer::Humidity,
+            'V' => XdrTransducer::Volume,
+            'G' => XdrTransducer::Generic,
+            'I' => XdrTransducer::Current,
+            'U' => XdrTransducer::Voltage,
+            'S' => XdrTransducer::Switch,
+            'L' => XdrTransducer::Salinity,
+            other => XdrTransducer::Other(other),
+        }
+    }
+
+    pub(crate) fn value(self) -> char {
+        match self {
+            XdrTransducer::Angular => 'A',
+            XdrTransducer::Temperature => 'C',
+            XdrTransducer::LinearDisplacement => 'D',
+            XdrTransducer::Frequency => 'F',
+            XdrTransducer::Force => 'N',
+            XdrTransducer::Pressure => 'P',
+            XdrTransducer::FlowRate => 'R',
+            XdrTransducer::Tachometer => 'T',
+            XdrTransducer::Humidity => 'H',
+            XdrTransducer::Volume => 'V',
+            XdrTransducer::Generic => 'G',
+            XdrTransducer::Current => 'I',
+            XdrTransducer::Voltage => 'U',
+            XdrTransducer::Switch => 'S',
+            XdrTransducer::Salinity => 'L',
+            XdrTransducer::Other(other) => other,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Represents the unit of an XDR measurement
+pub enum XdrUnit {
+    Degrees,
+    Celsius,
+    Meters,
+    CubicMeters,
+    Hertz,
+    Newtons,
+    Bars,
+    Pascals,
+    LitersPerSecond,
+    RevolutionsPerMinute,
+    Percent,
+    Amperes,
+    Volts,
+    PartsPerThousand,
+    /// Any other, usually manufacturer specific, unit
+    Other(char),
+}
+
+impl XdrUnit {
+    /// The same unit letter means different units depending on the transducer,
+    /// e.g. P is pascal for pressure but percent for humidity
+    pub(crate) fn from_char(transducer: Option<XdrTransducer>, unit: char) -> Self {
+        match (transducer, unit) {
+            (Some(XdrTransducer::Humidity), 'P') => XdrUnit::Percent,
+            (Some(XdrTransducer::Volume), 'M') => XdrUnit::CubicMeters,
+            (_, 'D') => XdrUnit::Degrees,
+            (_, 'C') => XdrUnit::Celsius,
+            (_, 'M') => XdrUnit::Meters,
+            (_, 'H') => XdrUnit::Hertz,
+            (_, 'N') => XdrUnit::Newtons,
+            (_, 'B') => XdrUnit::Bars,
+            (_, 'P') => XdrUnit::Pascals,
+            (_, 'L') => XdrUnit::LitersPerSecond,
+            (_, 'R') => XdrUnit::RevolutionsPerMinute,
+            (_, 'A') => XdrUnit::Amperes,
+            (_, 'V') => XdrUnit::Volts,
+            (_, 'S') => XdrUnit::PartsPerThousand,
+            (_, other) => XdrUnit::Other(other),
+        }
+    }
+
+    pub(crate) fn value(self) -> char {
+        match self {
+            XdrUnit::Degrees => 'D',
+            XdrUnit::Celsius => 'C',
+            XdrUnit::Meters | XdrUnit::CubicMeters => 'M',
+            XdrUnit::Hertz => 'H',
+            XdrUnit::Newtons => 'N',
+            XdrUnit::Bars => 'B',
+            XdrUnit::Pascals | XdrUnit::Percent => 'P',
+            XdrUnit::LitersPerSecond => 'L',
+            XdrUnit::RevolutionsPerMinute => 'R',
+            XdrUnit::Amperes => 'A',
+            XdrUnit::Volts => 'V',
+            XdrUnit::PartsPerThousand => 'S',
+            XdrUnit::Other(other) => other,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A single measurement of an XDR sentence
+pub struct XdrMeasurement<'a> {
+    pub transducer: Option<XdrTransducer>,
+    pub value: Option<f32>,
+    pub unit: Option<XdrUnit>,
+    pub name: Option<&'a [u8]>,
+}
+
+/// An iterator over the measurements of an XDR sentence
+#[derive(Debug, Clone)]
+pub struct XdrMeasurements<'a> {
+    fields: core::slice::Split<'a, u8, fn(&u8) -> bool>,
+}
+
+impl<'a> Iterator for XdrMeasurements<'a> {
+    type Item = XdrMeasurement<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let fields = [
+            self.fields.next()?,
+            self.fields.next()?,
+            self.fields.next()?,
+            self.fields.next()?,
+        ];
+
+        // The measurements were already verified while parsing the sentence
+        parsers::xdr::parse_measurement(fields).ok()
+    }
+}
+
+fn is_comma(byte: &u8) -> bool {
+    *byte == b','
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Represents the navigation status reported by PUBX,00
+pub enum PubxNavigationStatus {
+    NoFix,
+    DeadReckoning,
+    Standalone2D,
+    Standalone3D,
+    Differential2D,
+    Differential3D,
+    /// GNSS combined with dead reckoning
+    CombinedDeadReckoning,
+    TimeOnly,
+}
+
+impl PubxNavigationStatus {
+    pub(crate) fn try_from(status: &[u8]) -> Result<Self, NmeaSentenceError<'_>> {
+        match status {
+            b"NF" => Ok(PubxNavigationStatus::NoFix),
+            b"DR" => Ok(PubxNavigationStatus::DeadReckoning),
+            b"G2" => Ok(PubxNavigationStatus::Standalone2D),
+            b"G3" => Ok(PubxNavigationStatus::Standalone3D),
+            b"D2" => Ok(PubxNavigationStatus::Differential2D),
+            b"D3" => Ok(PubxNavigationStatus::Differential3D),
+            b"RK" => Ok(PubxNavigationStatus::CombinedDeadReckoning),
+            b"TT" => Ok(PubxNavigationStatus::TimeOnly),
+            _ => Err(NmeaSentenceError::GeneralParsingError),
+        }
+    }
+
+    #[allow(unused)]
+    pub(crate) fn value(self) -> &'static str {
+        match self {
+            PubxNavigationStatus::NoFix => "NF",
+            PubxNavigationStatus::DeadReckoning => "DR",
+            PubxNavigationStatus::Standalone2D => "G2",
+            PubxNavigationStatus::Standalone3D => "G3",
+            PubxNavigationStatus::Differential2D => "D2",
+            PubxNavigationStatus::Differential3D => "D3",
+            PubxNavigationStatus::CombinedDeadReckoning => "RK",
+            PubxNavigationStatus::TimeOnly => "TT",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A single satellite of a PUBX,03 sentence
+pub struct PubxSatellite {
+    pub sat_id: u16,
+    pub status: PubxSatelliteStatus,
+    pub azimuth: Option<u16>,
+    pub elevation: Option<i8>,
+    pub cno: Option<u8>,
+    /// The time the carrier has been locked for in seconds, capped at 64
+    pub lock_time: Option<u8>,
+}
+
+/// An iterator over the satellites of a PUBX,03 sentence
+#[derive(Debug, Clone)]
+pub struct PubxSatellites<'a> {
+    fields: core::slice::Split<'a, u8, fn(&u8) -> bool>,
+}
+
+impl Iterator for PubxSatellites<'_> {
+    type Item = PubxSatellite;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let fields = [
+            self.fields.next()?,
+            self.fields.next()?,
+            self.fields.next()?,
+            self.fields.next()?,
+            self.fields.next()?,
+            self.fields.next()?,
+        ];
+
+        // The satellites were already verified while parsing the sentence
+        parsers::pubx::parse_satellite(fields).ok()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Represents the offset of the local time zone from UTC,
+/// minutes carry the same sign as hours
+pub struct LocalZone {
+    pub hours: i8,
+    pub minutes: i8,
+}
+
+impl LocalZone {
+    /// Returns the whole offset from UTC in minutes
+    pub fn offset_minutes(&self) -> i16 {
+        i16::from(self.hours) * 60 + i16::from(self.minutes)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Represents a depth given in feet, meters and fathoms as it
+/// is used by the DBK, DBS and DBT sentences
+pub struct DepthData {
+    pub feet: Option<f32>,
+    pub meters: Option<f32>,
+    pub fathoms: Option<f32>,
+}
+
+impl DepthData {
+    /// Returns the depth in the requested unit, if the sentence did not
+    /// contain it the depth is converted from one of the other units
+    pub fn depth(&self, unit: DepthUnit) -> Option<f32> {
+        let meters = match (self.meters, self.feet, self.fathoms) {
+            (Some(meters), _, _) => meters,
+            (None, Some(feet), _) => feet * 0.3048,
+            (None, None, Some(fathoms)) => fathoms * 1.8288,
+            (None, None, None) => return None,
+        };
+
+        Some(match unit {
+            DepthUnit::Feet => self.feet.unwrap_or(meters / 0.3048),
+            DepthUnit::Meters => meters,
+            DepthUnit::Fathoms => self.fathoms.unwrap_or(meters / 1.8288),
+        })
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 /// Represents a GSV satellite
 pub struct GsvSatellite {
@@ -214,20 +777,263 @@ pub struct GsvSatellite {
     pub snr: Option<u8>,
 }
 
+/// The maximum amount of waypoints a single RTE or ROO sentence can list without
+/// the alloc feature, this is the most a sentence of the maximum length can contain
+/// if every identifier is left empty
+#[cfg(not(feature = "alloc"))]
+pub const MAX_RTE_WAYPOINTS: usize = 91;
+
+/// The waypoint identifiers listed by an RTE or ROO sentence, empty identifiers
+/// are kept as None so every waypoint keeps its position in the list
+#[cfg(feature = "alloc")]
+pub type RteWaypoints<'a> = alloc::vec::Vec<Option<&'a [u8]>>;
+
+/// The waypoint identifiers listed by an RTE or ROO sentence, empty identifiers
+/// are kept as None so every waypoint keeps its position in the list. Since the
+/// alloc feature is disabled only the bounds of every identifier inside of the
+/// sentence are stored, with a fixed capacity.
+#[cfg(not(feature = "alloc"))]
+#[derive(Clone, Copy)]
+pub struct RteWaypoints<'a> {
+    list: &'a [u8],
+    bounds: [(u8, u8); MAX_RTE_WAYPOINTS],
+    length: usize,
+}
+
+#[cfg(not(feature = "alloc"))]
+impl<'a> RteWaypoints<'a> {
+    /// Splits a comma separated list of waypoint identifiers, an empty list
+    /// does not contain any waypoints
+    pub(crate) fn new(list: &'a [u8]) -> Result<Self, NmeaSentenceError<'a>> {
+        let mut waypoints = RteWaypoints {
+            list,
+            bounds: [(0, 0); MAX_RTE_WAYPOINTS],
+            length: 0,
+        };
+        if list.is_empty() {
+            return Ok(waypoints);
+        }
+        if list.len() > usize::from(u8::MAX) {
+            return Err(NmeaSentenceError::GeneralParsingError);
+        }
+
+        let mut start = 0;
+        for end in (0..list.len())
+            .filter(|&index| list[index] == b',')
+            .chain(Some(list.len()))
+        {
+            if waypoints.length == MAX_RTE_WAYPOINTS {
+                return Err(NmeaSentenceError::GeneralParsingError);
+            }
+            waypoints.bounds[waypoints.length] = (start as u8, end as u8);
+            waypoints.length += 1;
+            start = end + 1;
+        }
+
+        Ok(waypoints)
+    }
+
+    /// Returns the amount of waypoints
+    pub fn len(&self) -> usize {
+        self.length
+    }
+
+    /// Returns true if the sentence did not list any waypoints
+    pub fn is_empty(&self) -> bool {
+        self.length == 0
+    }
+
+    /// Returns the waypoint at the given position, which is None
+    /// if its identifier was left empty
+    pub fn get(&self, index: usize) -> Option<Option<&'a [u8]>> {
+        if index >= self.length {
+            return None;
+        }
+
+        let (start, end) = self.bounds[index];
+        match &self.list[usize::from(start)..usize::from(end)] {
+            [] => Some(None),
+            waypoint => Some(Some(waypoint)),
+        }
+    }
+
+    /// Returns an iterator over all waypoints in the order they were listed
+    pub fn iter(&self) -> impl Iterator<Item = Option<&'a [u8]>> + '_ {
+        (0..self.length).filter_map(move |index| self.get(index))
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl core::fmt::Debug for RteWaypoints<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(not(feature = "alloc"))]
+impl PartialEq for RteWaypoints<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+/// Iterates over the waypoints of an RTE or ROO sentence regardless of the alloc feature
+pub(crate) fn iter_waypoints<'w, 'a>(
+    waypoints: &'w RteWaypoints<'a>,
+) -> impl Iterator<Item = Option<&'a [u8]>> + 'w {
+    #[cfg(feature = "alloc")]
+    let waypoints = waypoints.iter().copied();
+    #[cfg(not(feature = "alloc"))]
+    let waypoints = waypoints.iter();
+
+    waypoints
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct AamData {}
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct AbkData {}
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct AckData {}
+pub struct AamData<'a> {
+    pub arrival_circle: Option<ArrivalStatus>,
+    pub perpendicular_passed: Option<ArrivalStatus>,
+    pub radius: Option<f32>,
+    pub radius_unit: Option<DistanceUnit>,
+    pub waypoint: Option<&'a [u8]>,
+}
+/// Acknowledges an addressed or broadcast AIS binary message
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AbkData {
+    /// The MMSI of the addressed station, empty for broadcasts
+    pub mmsi: Option<u32>,
+    pub channel: Option<AisChannel>,
+    pub message_id: Option<u8>,
+    pub sequence_number: Option<u8>,
+    pub acknowledgement: Option<AbkAcknowledgement>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct AlmData {}
+pub struct AckData {
+    pub alarm_id: Option<u16>,
+}
+/// The almanac words are kept in the scaling of the GPS navigation message,
+/// the methods convert them into seconds, semicircles and meters
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct ApaData {}
+pub struct AlmData {
+    pub number_of_sentences: Option<u16>,
+    pub sentence_num: Option<u16>,
+    pub sat_id: Option<u8>,
+    pub week: Option<u16>,
+    pub health: Option<u8>,
+    pub eccentricity: Option<u16>,
+    pub reference_time: Option<u8>,
+    /// The inclination relative to 0.3 semicircles
+    pub inclination_offset: Option<i16>,
+    pub rate_of_right_ascension: Option<i16>,
+    pub sqrt_semi_major_axis: Option<u32>,
+    pub argument_of_perigee: Option<i32>,
+    pub longitude_of_ascending_node: Option<i32>,
+    pub mean_anomaly: Option<i32>,
+    /// Only the lower 11 bits are used
+    pub clock_bias: Option<i16>,
+    /// Only the lower 11 bits are used
+    pub clock_drift: Option<i16>,
+}
+
+const TWO_POW_11: f32 = (1u64 << 11) as f32;
+const TWO_POW_19: f32 = (1u64 << 19) as f32;
+const TWO_POW_20: f32 = (1u64 << 20) as f32;
+const TWO_POW_21: f32 = (1u64 << 21) as f32;
+const TWO_POW_23: f32 = (1u64 << 23) as f32;
+const TWO_POW_38: f32 = (1u64 << 38) as f32;
+
+impl AlmData {
+    /// Returns the eccentricity of the orbit
+    pub fn eccentricity_ratio(&self) -> Option<f32> {
+        self.eccentricity.map(|raw| f32::from(raw) / TWO_POW_21)
+    }
+
+    /// Returns the reference time of the almanac in seconds of the GPS week
+    pub fn reference_time_seconds(&self) -> Option<u32> {
+        self.reference_time.map(|raw| u32::from(raw) << 12)
+    }
+
+    /// Returns the inclination of the orbit in semicircles
+    pub fn inclination_semicircles(&self) -> Option<f32> {
+        self.inclination_offset
+            .map(|raw| 0.3 + f32::from(raw) / TWO_POW_19)
+    }
+
+    /// Returns the rate of right ascension in semicircles per second
+    pub fn rate_of_right_ascension_semicircles(&self) -> Option<f32> {
+        self.rate_of_right_ascension
+            .map(|raw| f32::from(raw) / TWO_POW_38)
+    }
+
+    /// Returns the square root of the semi major axis in square root meters
+    pub fn sqrt_semi_major_axis_meters(&self) -> Option<f32> {
+        self.sqrt_semi_major_axis.map(|raw| raw as f32 / TWO_POW_11)
+    }
+
+    /// Returns the argument of perigee in semicircles
+    pub fn argument_of_perigee_semicircles(&self) -> Option<f32> {
+        self.argument_of_perigee.map(|raw| raw as f32 / TWO_POW_23)
+    }
+
+    /// Returns the longitude of the ascending node in semicircles
+    pub fn longitude_of_ascending_node_semicircles(&self) -> Option<f32> {
+        self.longitude_of_ascending_node
+            .map(|raw| raw as f32 / TWO_POW_23)
+    }
+
+    /// Returns the mean anomaly at the reference time in semicircles
+    pub fn mean_anomaly_semicircles(&self) -> Option<f32> {
+        self.mean_anomaly.map(|raw| raw as f32 / TWO_POW_23)
+    }
+
+    /// Returns the clock bias in seconds
+    pub fn clock_bias_seconds(&self) -> Option<f32> {
+        self.clock_bias.map(|raw| f32::from(raw) / TWO_POW_20)
+    }
+
+    /// Returns the clock drift in seconds per second
+    pub fn clock_drift_seconds(&self) -> Option<f32> {
+        self.clock_drift.map(|raw| f32::from(raw) / TWO_POW_38)
+    }
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct ApbData {}
+pub struct ApaData<'a> {
+    /// Invalid if a LORAN-C blink or SNR warning occurred
+    pub status: Option<DataValidity>,
+    /// Invalid if a LORAN-C cycle lock warning occurred
+    pub cycle_lock_status: Option<DataValidity>,
+    pub cross_track_error: Option<f32>,
+    pub steer_direction: Option<SteerDirection>,
+    pub cross_track_unit: Option<DistanceUnit>,
+    pub arrival_circle: Option<ArrivalStatus>,
+    pub perpendicular_passed: Option<ArrivalStatus>,
+    pub bearing_origin_to_dest: Option<f32>,
+    pub bearing_origin_to_dest_reference: Option<BearingReference>,
+    pub dest_waypoint: Option<&'a [u8]>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct BecData {}
+pub struct ApbData<'a> {
+    /// Invalid if a LORAN-C blink or SNR warning occurred
+    pub status: Option<DataValidity>,
+    /// Invalid if a LORAN-C cycle lock warning occurred
+    pub cycle_lock_status: Option<DataValidity>,
+    pub cross_track_error: Option<f32>,
+    pub steer_direction: Option<SteerDirection>,
+    pub cross_track_unit: Option<DistanceUnit>,
+    pub arrival_circle: Option<ArrivalStatus>,
+    pub perpendicular_passed: Option<ArrivalStatus>,
+    pub bearing_origin_to_dest: Option<f32>,
+    pub bearing_origin_to_dest_reference: Option<BearingReference>,
+    pub dest_waypoint: Option<&'a [u8]>,
+    pub bearing_to_dest: Option<f32>,
+    pub bearing_to_dest_reference: Option<BearingReference>,
+    pub heading_to_steer: Option<f32>,
+    pub heading_to_steer_reference: Option<BearingReference>,
+    /// The FAA mode indicator, only sent from NMEA 2.3 onwards
+    pub mode: Option<GnsMode>,
+}
+/// BEC carries the same fields as BWC but is based on dead reckoning
+pub type BecData<'a> = BwcData<'a>;
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct BodData<'a> {
     pub bearing_true: Option<f32>,
@@ -244,24 +1050,116 @@ pub struct BwcData<'a> {
     pub nautical_miles: Option<f32>,
     pub waypoint: Option<&'a [u8]>,
 }
+/// BWR carries the same fields as BWC but uses the rhumb line
+/// instead of the great circle
+pub type BwrData<'a> = BwcData<'a>;
+/// BWW carries the same fields as BOD but uses the bearing between
+/// two arbitrary waypoints instead of the one of the active leg
+pub type BwwData<'a> = BodData<'a>;
+pub type DbkData = DepthData;
+pub type DbsData = DepthData;
+pub type DbtData = DepthData;
+/// A single Decca line of position
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DeccaLine<'a> {
+    pub zone: Option<&'a [u8]>,
+    pub line_of_position: Option<f32>,
+    pub master_line_status: Option<DataValidity>,
+    /// Valid if the line is used for navigation
+    pub in_use: Option<DataValidity>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct BwrData {}
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct BwwData {}
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct DbkData {}
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct DbsData {}
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct DbtData {}
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct DcnData {}
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct DptData {}
+pub struct DcnData<'a> {
+    pub chain_id: Option<u8>,
+    pub red: DeccaLine<'a>,
+    pub green: DeccaLine<'a>,
+    pub purple: DeccaLine<'a>,
+    /// The position uncertainty in nautical miles
+    pub position_uncertainty: Option<f32>,
+    pub fix_basis: Option<DeccaFixBasis>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct DtmData {}
+pub struct DptData {
+    pub depth: Option<f32>,
+    pub offset: Option<f32>,
+    pub max_range: Option<f32>,
+}
+
+impl DptData {
+    /// Applies the transducer offset to the depth, a positive offset yields the
+    /// depth below the surface, a negative one the depth below the keel
+    pub fn depth_with_offset(&self) -> Option<f32> {
+        match (self.depth, self.offset) {
+            (Some(depth), Some(offset)) => Some(depth + offset),
+            _ => None,
+        }
+    }
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct FsiData {}
+pub struct DtmData<'a> {
+    pub local_datum: Option<Datum>,
+    pub subdivision: Option<&'a [u8]>,
+    /// Offset in minutes
+    pub lat_offset: Option<f32>,
+    pub lat_offset_direction: Option<LatitudeDirection>,
+    /// Offset in minutes
+    pub lon_offset: Option<f32>,
+    pub lon_offset_direction: Option<LongitudeDirection>,
+    /// Offset in meters
+    pub alt_offset: Option<f32>,
+    pub reference_datum: Option<Datum>,
+}
+
+impl DtmData<'_> {
+    /// Converts a position given in the local datum into the reference datum
+    /// by removing the offsets, missing offsets are treated as zero
+    pub fn to_reference_datum(&self, position: GpsPosition) -> GpsPosition {
+        let lat_offset = match (self.lat_offset, self.lat_offset_direction) {
+            (Some(offset), Some(LatitudeDirection::South)) => -offset / 60.,
+            (Some(offset), _) => offset / 60.,
+            (None, _) => 0.,
+        };
+        let lon_offset = match (self.lon_offset, self.lon_offset_direction) {
+            (Some(offset), Some(LongitudeDirection::West)) => -offset / 60.,
+            (Some(offset), _) => offset / 60.,
+            (None, _) => 0.,
+        };
+
+        let lat = match position.lat_dir {
+            LatitudeDirection::North => position.lat,
+            LatitudeDirection::South => -position.lat,
+        } - lat_offset;
+        let lon = match position.lon_dir {
+            LongitudeDirection::East => position.lon,
+            LongitudeDirection::West => -position.lon,
+        } - lon_offset;
+
+        GpsPosition {
+            lat: if lat < 0. { -lat } else { lat },
+            lat_dir: if lat < 0. {
+                LatitudeDirection::South
+            } else {
+                LatitudeDirection::North
+            },
+            lon: if lon < 0. { -lon } else { lon },
+            lon_dir: if lon < 0. {
+                LongitudeDirection::West
+            } else {
+                LongitudeDirection::East
+            },
+        }
+    }
+}
+/// Frequencies are given in units of 100 Hz
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FsiData {
+    pub transmit_frequency: Option<u32>,
+    pub receive_frequency: Option<u32>,
+    pub mode: Option<FrequencyMode>,
+    /// From 0 for standby up to 9 for the highest power
+    pub power_level: Option<u8>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GbsData {
     pub time: Option<GpsTime>,
@@ -286,7 +1184,13 @@ pub struct GgaData {
     pub differential_station_id: Option<u16>,
 }
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct GlcData {}
+pub struct GlcData {
+    /// The group repetition interval in tens of microseconds
+    pub gri: Option<u16>,
+    /// The time of arrival of the master signal
+    pub master: Option<LoranTd>,
+    pub secondaries: [Option<LoranTd>; 5],
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GllData {
     pub position: GpsPosition,
@@ -312,9 +1216,39 @@ pub struct GnsData {
 pub struct GnsData {}
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct GrsData {}
+pub struct GrsData {
+    pub time: Option<GpsTime>,
+    pub mode: Option<GrsMode>,
+    pub residuals: [Option<f32>; 12],
+}
+
+impl GrsData {
+    /// Pairs the residuals with the satellite ids of a GSA sentence of the same fix,
+    /// both sentences list their satellites in the same order
+    pub fn residuals_by_satellite<'a>(
+        &'a self,
+        gsa: &'a GsaData,
+    ) -> impl Iterator<Item = (u8, f32)> + 'a {
+        gsa.satellites
+            .iter()
+            .zip(self.residuals.iter())
+            .filter_map(|(sat_id, residual)| match (sat_id, residual) {
+                (Some(sat_id), Some(residual)) => Some((*sat_id, *residual)),
+                _ => None,
+            })
+    }
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct GstData {}
+pub struct GstData {
+    pub time: Option<GpsTime>,
+    pub rms: Option<f32>,
+    pub semi_major_error: Option<f32>,
+    pub semi_minor_error: Option<f32>,
+    pub orientation: Option<f32>,
+    pub lat_error: Option<f32>,
+    pub lon_error: Option<f32>,
+    pub alt_error: Option<f32>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GsaData {
     pub selection_mode: Option<GsaSelectionMode>,
@@ -325,7 +1259,9 @@ pub struct GsaData {
     pub vdop: Option<f32>,
 }
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct GtdData {}
+pub struct GtdData {
+    pub time_differences: [Option<LoranTd>; 5],
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct GsvData {
     pub number_of_sentences: Option<u16>,
@@ -333,38 +1269,181 @@ pub struct GsvData {
     pub sats_in_view: Option<u8>,
     pub sats_info: [Option<GsvSatellite>; 4],
 }
+/// Represents a TRANSIT satellite fix
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct GxaData {}
+pub struct GxaData<'a> {
+    pub time: Option<GpsTime>,
+    pub position: Option<GpsPosition>,
+    pub waypoint: Option<&'a [u8]>,
+    pub sat_id: Option<u16>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct HdgData {}
+pub struct HdgData {
+    pub heading_magnetic_sensor: Option<f32>,
+    pub deviation: Option<f32>,
+    pub deviation_direction: Option<LongitudeDirection>,
+    pub variation: Option<f32>,
+    pub variation_direction: Option<LongitudeDirection>,
+}
+
+impl HdgData {
+    /// Applies the deviation to the sensor heading, a missing deviation is treated as zero
+    pub fn heading_magnetic(&self) -> Option<f32> {
+        let heading = self.heading_magnetic_sensor?;
+        let deviation = match (self.deviation, self.deviation_direction) {
+            (Some(deviation), Some(direction)) => signed_by_direction(deviation, direction),
+            _ => 0.,
+        };
+
+        Some(normalize_heading(heading + deviation))
+    }
+
+    /// Applies deviation and variation to the sensor heading, easterly values are
+    /// added and westerly ones subtracted. Returns None if the variation is unknown.
+    pub fn heading_true(&self) -> Option<f32> {
+        let variation = match (self.variation, self.variation_direction) {
+            (Some(variation), Some(direction)) => signed_by_direction(variation, direction),
+            _ => return None,
+        };
+
+        Some(normalize_heading(self.heading_magnetic()? + variation))
+    }
+}
+
+fn signed_by_direction(value: f32, direction: LongitudeDirection) -> f32 {
+    match direction {
+        LongitudeDirection::East => value,
+        LongitudeDirection::West => -value,
+    }
+}
+
+fn normalize_heading(heading: f32) -> f32 {
+    let heading = heading % 360.;
+    if heading < 0. {
+        heading + 360.
+    } else {
+        heading
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct HdmData {}
+pub struct HdmData {
+    pub heading_magnetic: Option<f32>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct HdtData {
     pub heading_true: Option<f32>,
 }
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct HscData {}
+pub struct HscData {
+    pub heading_true: Option<f32>,
+    pub heading_magnetic: Option<f32>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct LcdData {}
+pub struct LcdData {
+    /// The group repetition interval in tens of microseconds
+    pub gri: Option<u16>,
+    pub master: LoranSignal,
+    pub secondaries: [LoranSignal; 5],
+}
+/// Temperatures are given in degrees Celsius, wind directions in degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MdaData {
+    pub pressure_inches: Option<f32>,
+    pub pressure_bars: Option<f32>,
+    pub air_temperature: Option<f32>,
+    pub water_temperature: Option<f32>,
+    /// Relative humidity in percent
+    pub relative_humidity: Option<f32>,
+    /// Absolute humidity in percent
+    pub absolute_humidity: Option<f32>,
+    pub dew_point: Option<f32>,
+    pub wind_direction_true: Option<f32>,
+    pub wind_direction_magnetic: Option<f32>,
+    pub wind_speed_knots: Option<f32>,
+    pub wind_speed_mps: Option<f32>,
+}
+/// Commands an MSK beacon receiver
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MskData {
+    /// The beacon frequency in kHz
+    pub frequency: Option<f32>,
+    pub frequency_mode: Option<GsaSelectionMode>,
+    /// The beacon bit rate in bits per second
+    pub bit_rate: Option<u16>,
+    pub bit_rate_mode: Option<GsaSelectionMode>,
+    /// The interval between MSS status sentences in seconds
+    pub status_interval: Option<f32>,
+    /// Only sent by receivers with more than one channel
+    pub channel: Option<u8>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct MskData {}
+pub struct MtwData {
+    pub temperature: Option<f32>,
+    pub unit: Option<TemperatureUnit>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct MtwData {}
+pub struct MwdData {
+    pub direction_true: Option<f32>,
+    pub direction_magnetic: Option<f32>,
+    pub speed_knots: Option<f32>,
+    pub speed_mps: Option<f32>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct MwvData {}
+pub struct MwvData {
+    pub angle: Option<f32>,
+    pub reference: Option<WindReference>,
+    pub speed: Option<f32>,
+    pub speed_unit: Option<SpeedUnit>,
+    pub status: Option<DataValidity>,
+}
+
+impl MwvData {
+    /// Returns the wind speed converted into the requested unit
+    pub fn speed_in(&self, unit: SpeedUnit) -> Option<f32> {
+        match (self.speed, self.speed_unit) {
+            (Some(speed), Some(speed_unit)) => Some(speed_unit.convert(speed, unit)),
+            _ => None,
+        }
+    }
+}
+/// The lane of a single Omega station pair
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct OlnData {}
+pub struct OmegaLane<'a> {
+    pub pair: Option<&'a [u8]>,
+    pub lane: Option<u16>,
+    /// The position inside of the lane in hundredths of a lane
+    pub centilane: Option<u16>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct OsdData {}
+pub struct OlnData<'a> {
+    pub lanes: [OmegaLane<'a>; 3],
+}
+/// Represents the motion of the own ship
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct RooData {}
+pub struct OsdData {
+    pub heading_true: Option<f32>,
+    pub status: Option<DataValidity>,
+    pub course_true: Option<f32>,
+    pub course_reference: Option<MotionReference>,
+    pub speed: Option<f32>,
+    pub speed_reference: Option<MotionReference>,
+    pub set_true: Option<f32>,
+    pub drift: Option<f32>,
+    /// The unit of both the speed and the drift
+    pub speed_unit: Option<SpeedUnit>,
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct RooData<'a> {
+    pub waypoints: RteWaypoints<'a>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct RmaData {
     pub status: Option<RmStatus>,
     pub position: GpsPosition,
-    pub time_diff_a: Option<f32>,
-    pub time_diff_b: Option<f32>,
+    pub time_diff_a: Option<LoranTd>,
+    pub time_diff_b: Option<LoranTd>,
     pub speed: Option<f32>,
     pub heading: Option<f32>,
     pub magnetic_variation: Option<f32>,
@@ -396,25 +1475,113 @@ pub struct RmcData {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct RotData {}
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct RpmData {}
+pub struct RotData {
+    pub rate_of_turn: Option<f32>,
+    pub status: Option<DataValidity>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct RsaData {}
+pub struct RpmData {
+    pub source: Option<RpmSource>,
+    /// The number of the shaft or engine, counted from center to starboard
+    /// with odd numbers and from center to port with even ones
+    pub number: Option<u8>,
+    /// Negative values mean a counter clockwise rotation
+    pub rpm: Option<f32>,
+    /// The propeller pitch in percent, negative values mean astern
+    pub pitch: Option<f32>,
+    pub status: Option<DataValidity>,
+}
+/// Rudder angles are given in degrees, negative values mean the
+/// bow turns to port
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RsaData {
+    pub starboard_angle: Option<f32>,
+    pub starboard_status: Option<DataValidity>,
+    /// Only sent by vessels with two rudders
+    pub port_angle: Option<f32>,
+    pub port_status: Option<DataValidity>,
+}
+/// The markers belonging to one origin of a radar display
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct RsdData {}
+pub struct RadarOrigin {
+    pub range: Option<f32>,
+    pub bearing: Option<f32>,
+    /// The range of the variable range marker
+    pub variable_range_marker: Option<f32>,
+    /// The bearing of the electronic bearing line
+    pub bearing_line: Option<f32>,
+}
+
+/// Ranges are given in the range unit, bearings in degrees
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RsdData {
+    pub origins: [RadarOrigin; 2],
+    pub cursor_range: Option<f32>,
+    pub cursor_bearing: Option<f32>,
+    pub range_scale: Option<f32>,
+    pub range_unit: Option<DistanceUnit>,
+    pub display_rotation: Option<DisplayRotation>,
+}
+#[derive(Debug, Clone, PartialEq)]
+pub struct RteData<'a> {
+    pub number_of_sentences: Option<u16>,
+    pub sentence_num: Option<u16>,
+    pub mode: Option<RteMode>,
+    pub route_id: Option<&'a [u8]>,
+    pub waypoints: RteWaypoints<'a>,
+}
+/// A frequency scanned by a radio, given in units of 100 Hz
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct RteData {}
+pub struct ScanFrequency {
+    pub frequency: u32,
+    pub mode: Option<FrequencyMode>,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct SfiData {}
+pub struct SfiData {
+    pub number_of_sentences: Option<u16>,
+    pub sentence_num: Option<u16>,
+    pub frequencies: [Option<ScanFrequency>; 6],
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct StnData {
     pub talker_id: u8,
 }
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct TllData {}
+pub struct TllData<'a> {
+    pub target_number: Option<u8>,
+    pub position: Option<GpsPosition>,
+    pub target_name: Option<&'a [u8]>,
+    pub time: Option<GpsTime>,
+    pub status: Option<TargetStatus>,
+    /// True if this target is used to determine the speed of the own ship
+    pub reference_target: bool,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct TtmData {}
+pub struct TtmData<'a> {
+    pub target_number: Option<u8>,
+    pub distance: Option<f32>,
+    pub bearing: Option<f32>,
+    pub bearing_reference: Option<TargetReference>,
+    pub speed: Option<f32>,
+    pub course: Option<f32>,
+    pub course_reference: Option<TargetReference>,
+    /// The distance at the closest point of approach
+    pub cpa_distance: Option<f32>,
+    /// The time to the closest point of approach in minutes,
+    /// negative values mean the target moves away
+    pub tcpa: Option<f32>,
+    /// Distances are given in this unit, speeds in this unit per hour
+    pub unit: Option<DistanceUnit>,
+    pub target_name: Option<&'a [u8]>,
+    pub status: Option<TargetStatus>,
+    /// True if this target is used to determine the speed of the own ship
+    pub reference_target: bool,
+    /// Only sent from NMEA 3.0 onwards
+    pub time: Option<GpsTime>,
+    /// Only sent from NMEA 3.0 onwards
+    pub acquisition: Option<TargetAcquisition>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct VbwData {
     pub lon_water_speed: Option<f32>,
@@ -424,14 +1591,50 @@ pub struct VbwData {
     pub transverse_ground_speed: Option<f32>,
     pub ground_validity: Option<DataValidity>,
 }
+/// An encapsulated AIS message or a fragment of one, the payload is still
+/// armored. See the ais module for reassembling and decoding it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VdmData<'a> {
+    pub fragment_count: Option<u16>,
+    pub fragment_number: Option<u16>,
+    /// Ties the fragments of a multi sentence message together
+    pub message_id: Option<u8>,
+    pub channel: Option<AisChannel>,
+    pub payload: &'a [u8],
+    /// The amount of bits appended to the last payload character as padding
+    pub fill_bits: Option<u8>,
+}
+/// VDO carries the messages of the own vessel with the same fields as VDM
+pub type VdoData<'a> = VdmData<'a>;
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct VdrData {}
-#[derive(Debug, Clone, Copy, PartialEq)]
-pub struct VhwData {}
+pub struct VdrData {
+    pub set_true: Option<f32>,
+    pub set_magnetic: Option<f32>,
+    pub drift_knots: Option<f32>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct VlwData {}
+pub struct VhwData {
+    pub heading_true: Option<f32>,
+    pub heading_magnetic: Option<f32>,
+    pub speed_knots: Option<f32>,
+    pub speed_kmh: Option<f32>,
+}
+/// All distances are given in nautical miles
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VlwData {
+    pub total_water_distance: Option<f32>,
+    pub trip_water_distance: Option<f32>,
+    /// Only sent from NMEA 3.0 onwards
+    pub total_ground_distance: Option<f32>,
+    /// Only sent from NMEA 3.0 onwards
+    pub trip_ground_distance: Option<f32>,
+}
+/// Negative speeds mean the vessel moves downwind
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct VpwData {}
+pub struct VpwData {
+    pub speed_knots: Option<f32>,
+    pub speed_mps: Option<f32>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct VtgData {
     pub bearing_true: Option<f32>,
@@ -440,28 +1643,249 @@ pub struct VtgData {
     pub speed_kmh: Option<f32>,
 }
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct VwrData {}
+pub struct VwrData {
+    pub angle: Option<f32>,
+    pub side: Option<WindSide>,
+    pub speed_knots: Option<f32>,
+    pub speed_mps: Option<f32>,
+    pub speed_kmh: Option<f32>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct WcvData {}
+pub struct WcvData<'a> {
+    pub velocity_knots: Option<f32>,
+    pub waypoint: Option<&'a [u8]>,
+    /// The FAA mode indicator, only sent from NMEA 2.3 onwards
+    pub mode: Option<GnsMode>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct WncData {}
+pub struct WncData<'a> {
+    pub nautical_miles: Option<f32>,
+    pub kilometers: Option<f32>,
+    pub to_waypoint: Option<&'a [u8]>,
+    pub from_waypoint: Option<&'a [u8]>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct WplData<'a> {
     pub position: Option<GpsPosition>,
     pub waypoint_name: Option<&'a [u8]>,
 }
+/// XDR sentences list a variable amount of measurements, they are kept in
+/// their raw form and parsed while iterating over them
+#[derive(Clone, Copy)]
+pub struct XdrData<'a> {
+    pub(crate) measurements: &'a [u8],
+}
+
+impl<'a> XdrData<'a> {
+    /// Returns an iterator over all measurements in the order they were sent
+    pub fn measurements(&self) -> XdrMeasurements<'a> {
+        XdrMeasurements {
+            fields: self.measurements.split(is_comma as fn(&u8) -> bool),
+        }
+    }
+}
+
+impl core::fmt::Debug for XdrData<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_list().entries(self.measurements()).finish()
+    }
+}
+
+impl PartialEq for XdrData<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.measurements().eq(other.measurements())
+    }
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct XdrData {}
+pub struct XteData {
+    /// Invalid if a LORAN-C blink or SNR warning occurred
+    pub status: Option<DataValidity>,
+    /// Invalid if a LORAN-C cycle lock warning occurred
+    pub cycle_lock_status: Option<DataValidity>,
+    pub cross_track_error: Option<f32>,
+    pub steer_direction: Option<SteerDirection>,
+    pub cross_track_unit: Option<DistanceUnit>,
+    /// The FAA mode indicator, only sent from NMEA 2.3 onwards
+    pub mode: Option<GnsMode>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct XteData {}
+pub struct XtrData {
+    pub cross_track_error: Option<f32>,
+    pub steer_direction: Option<SteerDirection>,
+    pub cross_track_unit: Option<DistanceUnit>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct XtrData {}
+pub struct ZdaData {
+    pub time: Option<GpsTime>,
+    /// Unlike the date of RMC this one contains the full four digit year
+    pub date: Option<GpsDate>,
+    pub local_zone: Option<LocalZone>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct ZdaData {}
+pub struct ZfoData<'a> {
+    pub time: Option<GpsTime>,
+    /// The time elapsed since leaving the origin waypoint, hours may exceed 24
+    pub elapsed_time: Option<GpsTime>,
+    pub origin_waypoint: Option<&'a [u8]>,
+}
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct ZfoData {}
+pub struct ZtgData<'a> {
+    pub time: Option<GpsTime>,
+    /// The time left until reaching the destination waypoint, hours may exceed 24
+    pub time_to_go: Option<GpsTime>,
+    pub destination_waypoint: Option<&'a [u8]>,
+}
+
+// The data of the proprietary sentences the library comes with parsers for
+
+/// The position, velocity and accuracy estimates of PUBX,00
 #[derive(Debug, Clone, Copy, PartialEq)]
-pub struct ZtgData {}
+pub struct PubxPositionData {
+    pub time: Option<GpsTime>,
+    pub position: Option<GpsPosition>,
+    /// The altitude above the ellipsoid of the user datum in meters
+    pub altitude: Option<f32>,
+    pub navigation_status: Option<PubxNavigationStatus>,
+    /// The estimated horizontal accuracy in meters
+    pub horizontal_accuracy: Option<f32>,
+    /// The estimated vertical accuracy in meters
+    pub vertical_accuracy: Option<f32>,
+    pub speed_kmh: Option<f32>,
+    pub course: Option<f32>,
+    /// The vertical velocity in meters per second, positive values point downwards
+    pub vertical_velocity: Option<f32>,
+    pub age_of_differential: Option<f32>,
+    pub hdop: Option<f32>,
+    pub vdop: Option<f32>,
+    pub tdop: Option<f32>,
+    pub sats_used: Option<u8>,
+    pub dead_reckoning: bool,
+}
+
+/// The satellites tracked by the receiver as listed by PUBX,03
+#[derive(Clone, Copy)]
+pub struct PubxSatellitesData<'a> {
+    pub number_of_satellites: Option<u8>,
+    pub(crate) satellites: &'a [u8],
+}
+
+impl<'a> PubxSatellitesData<'a> {
+    /// Returns an iterator over all satellites in the order they were sent
+    pub fn satellites(&self) -> PubxSatellites<'a> {
+        PubxSatellites {
+            fields: self.satellites.split(is_comma as fn(&u8) -> bool),
+        }
+    }
+}
+
+impl core::fmt::Debug for PubxSatellitesData<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_struct("PubxSatellitesData")
+            .field("number_of_satellites", &self.number_of_satellites)
+            .field("satellites", &Satellites(*self))
+            .finish()
+    }
+}
+
+struct Satellites<'a>(PubxSatellitesData<'a>);
+
+impl core::fmt::Debug for Satellites<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+        f.debug_list().entries(self.0.satellites()).finish()
+    }
+}
+
+impl PartialEq for PubxSatellitesData<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.number_of_satellites == other.number_of_satellites
+            && self.satellites().eq(other.satellites())
+    }
+}
+
+/// The time of day and clock information of PUBX,04
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PubxTimeData {
+    pub time: Option<GpsTime>,
+    pub date: Option<GpsDate>,
+    /// The UTC time of week in seconds
+    pub utc_time_of_week: Option<f64>,
+    pub utc_week: Option<u16>,
+    pub leap_seconds: Option<u8>,
+    /// True if the leap seconds are the firmware default instead of
+    /// being taken from the almanac
+    pub leap_seconds_default: bool,
+    /// The receiver clock bias in nanoseconds
+    pub clock_bias: Option<i32>,
+    /// The receiver clock drift in nanoseconds per second
+    pub clock_drift: Option<f32>,
+    /// The time pulse granularity in nanoseconds
+    pub time_pulse_granularity: Option<u32>,
+}
+
+/// The u-blox PUBX messages the library comes with parsers for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PubxData<'a> {
+    /// PUBX,00
+    Position(PubxPositionData),
+    /// PUBX,03
+    Satellites(PubxSatellitesData<'a>),
+    /// PUBX,04
+    Time(PubxTimeData),
+}
+/// The acknowledgement a MediaTek receiver sends in reply to a command
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PmtkAckData {
+    /// The number of the command that is acknowledged
+    pub command: Option<u16>,
+    pub flag: Option<PmtkAckFlag>,
+}
+/// The firmware release a MediaTek receiver reports when queried with PMTK605
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PmtkFirmwareData<'a> {
+    pub release: Option<&'a [u8]>,
+    pub build_id: Option<&'a [u8]>,
+    pub product_model: Option<&'a [u8]>,
+    pub sdk_version: Option<&'a [u8]>,
+}
+/// The MediaTek PMTK messages the library comes with parsers for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PmtkData<'a> {
+    /// PMTK001
+    Ack(PmtkAckData),
+    /// PMTK705
+    Firmware(PmtkFirmwareData<'a>),
+}
+/// The estimated position errors of a Garmin receiver in meters
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PgrmeData {
+    pub horizontal_error: Option<f32>,
+    pub vertical_error: Option<f32>,
+    /// The overall spherical position error
+    pub spherical_error: Option<f32>,
+}
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PgrmzData {
+    /// The altitude in feet
+    pub altitude: Option<f32>,
+    /// Whether the altitude was entered by the user during a 2D fix
+    /// or determined by the receiver during a 3D fix
+    pub altitude_source: Option<PgrmzAltitudeSource>,
+}
+/// The map datum a Garmin receiver reports its positions in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PgrmmData<'a> {
+    pub datum: Option<&'a [u8]>,
+}
+/// The Garmin PGRM messages the library comes with parsers for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PgrmData<'a> {
+    /// PGRME
+    PositionError(PgrmeData),
+    /// PGRMZ
+    Altitude(PgrmzData),
+    /// PGRMM
+    Datum(PgrmmData<'a>),
+}
 
 macro_rules! sentence_parse_generator {
     ($sentence:ident : [$($TYPE:ident => $function:path,)+]) => {
@@ -469,12 +1893,14 @@ macro_rules! sentence_parse_generator {
             $(
                 SentenceType::$TYPE => Ok(SentenceData::$TYPE(parse_result_to_data($function($sentence.data))?)),
             )+
-            _ => Err(NmeaSentenceError::TypeNotImplementedError($sentence.sentence_type)),
+            SentenceType::Proprietary => Ok(SentenceData::Proprietary(
+                crate::proprietary::parse_builtin($sentence)?,
+            )),
         }
     }
 }
 
-fn parse_result_to_data<'a, Data>(
+pub(crate) fn parse_result_to_data<'a, Data>(
     parse_result: Result<(&'a [u8], Data), nom::Err<(&'a [u8], nom::error::ErrorKind)>>,
 ) -> Result<Data, NmeaSentenceError<'a>> {
     match parse_result {
@@ -485,77 +1911,81 @@ fn parse_result_to_data<'a, Data>(
 
 pub(crate) fn parse_sentence_data<'a>(
     general_sentence: GeneralSentence<'a>,
-) -> Result<SentenceData, NmeaSentenceError<'a>> {
+) -> Result<SentenceData<'a>, NmeaSentenceError<'a>> {
     sentence_parse_generator!(
         general_sentence: [
-            //AAM => parse_aam,
-            //ABK => parse_abk,
-            //ACK => parse_ack,
-            //ALM => parse_alm,
-            //APA => parse_apa,
-            //APB => parse_apb,
-            //BEC => parse_bec,
+            AAM => parsers::aam::parse_aam,
+            ABK => parsers::abk::parse_abk,
+            ACK => parsers::ack::parse_ack,
+            ALM => parsers::alm::parse_alm,
+            APA => parsers::apa::parse_apa,
+            APB => parsers::apb::parse_apb,
+            BEC => parsers::bwc::parse_bwc,
             BOD => parsers::bod::parse_bod,
             BWC => parsers::bwc::parse_bwc,
-            //BWR => parse_bwr,
-            //BWW => parse_bww,
-            //DBK => parse_dbk,
-            //DBS => parse_dbs,
-            //DBT => parse_dbt,
-            //DCN => parse_dcn,
-            //DPT => parse_dpt,
-            //DTM => parse_dtm,
-            //FSI => parse_fsi,
+            BWR => parsers::bwc::parse_bwc,
+            BWW => parsers::bod::parse_bod,
+            DBK => parsers::depth::parse_depth,
+            DBS => parsers::depth::parse_depth,
+            DBT => parsers::depth::parse_depth,
+            DCN => parsers::dcn::parse_dcn,
+            DPT => parsers::dpt::parse_dpt,
+            DTM => parsers::dtm::parse_dtm,
+            FSI => parsers::fsi::parse_fsi,
             GBS => parsers::gbs::parse_gbs,
             GGA => parsers::gga::parse_gga,
-            //GLC => parse_glc,
+            GLC => parsers::glc::parse_glc,
             GLL => parsers::gll::parse_gll,
             GNS => parsers::gns::parse_gns,
-            //GRS => parse_grs,
-            //GST => parse_gst,
+            GRS => parsers::grs::parse_grs,
+            GST => parsers::gst::parse_gst,
             GSA => parsers::gsa::parse_gsa,
             GSV => parsers::gsv::parse_gsv,
-            //GTD => parse_gtd,
-            //GXA => parse_gxa,
-            //HDG => parse_hdg,
-            //HDM => parse_hdm,
+            GTD => parsers::gtd::parse_gtd,
+            GXA => parsers::gxa::parse_gxa,
+            HDG => parsers::hdg::parse_hdg,
+            HDM => parsers::hdm::parse_hdm,
             HDT => parsers::hdt::parse_hdt,
-            //HSC => parse_hsc,
-            //LCD => parse_lcd,
-            //MSK => parse_msk,
-            //MTW => parse_mtw,
-            //MWV => parse_mwv,
-            //OLN => parse_oln,
-            //OSD => parse_osd,
-            //ROO => parse_roo,
+            HSC => parsers::hsc::parse_hsc,
+            LCD => parsers::lcd::parse_lcd,
+            MDA => parsers::mda::parse_mda,
+            MSK => parsers::msk::parse_msk,
+            MTW => parsers::mtw::parse_mtw,
+            MWD => parsers::mwd::parse_mwd,
+            MWV => parsers::mwv::parse_mwv,
+            OLN => parsers::oln::parse_oln,
+            OSD => parsers::osd::parse_osd,
+            ROO => parsers::roo::parse_roo,
             RMA => parsers::rma::parse_rma,
             RMB => parsers::rmb::parse_rmb,
             RMC => parsers::rmc::parse_rmc,
-            //ROT => parse_rot,
-            //RPM => parse_rpm,
-            //RSA => parse_rsa,
-            //RSD => parse_rsd,
-            //RTE => parse_rte,
-            //SFI => parse_sfi,
+            ROT => parsers::rot::parse_rot,
+            RPM => parsers::rpm::parse_rpm,
+            RSA => parsers::rsa::parse_rsa,
+            RSD => parsers::rsd::parse_rsd,
+            RTE => parsers::rte::parse_rte,
+            SFI => parsers::sfi::parse_sfi,
             STN => parsers::stn::parse_stn,
-            //TLL => parse_tll,
-            //TTM => parse_ttm,
+            TLL => parsers::tll::parse_tll,
+            TTM => parsers::ttm::parse_ttm,
             VBW => parsers::vbw::parse_vbw,
-            //VDR => parse_vdr,
-            //VHW => parse_vhw,
-            //VLW => parse_vlw,
-            //VPW => parse_vpw,
+            VDM => parsers::vdm::parse_vdm,
+            VDO => parsers::vdm::parse_vdm,
+            VDR => parsers::vdr::parse_vdr,
+            VHW => parsers::vhw::parse_vhw,
+            VLW => parsers::vlw::parse_vlw,
+            VPW => parsers::vpw::parse_vpw,
             VTG => parsers::vtg::parse_vtg,
-            //VWR => parse_vwr,
-            //WCV => parse_wcv,
-            //WNC => parse_wnc,
+            VWR => parsers::vwr::parse_vwr,
+            WCV => parsers::wcv::parse_wcv,
+            WNC => parsers::wnc::parse_wnc,
             WPL => parsers::wpl::parse_wpl,
-            //XDR => parse_xdr,
-            //XTE => parse_xte,
-            //XTR => parse_xtr,
-            //ZDA => parse_zda,
-            //ZFO => parse_zfo,
-            //ZTG => parse_ztg,
+            XDR => parsers::xdr::parse_xdr,
+            XTE => parsers::xte::parse_xte,
+            XTR => parsers::xtr::parse_xtr,
+            ZDA => parsers::zda::parse_zda,
+            ZFO => parsers::zfo::parse_zfo,
+            ZTG => parsers::ztg::parse_ztg,
         ]
     )
 }
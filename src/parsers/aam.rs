@@ -0,0 +1,40 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_aam<'a>(
+    sentence: (
+        Option<char>,
+        Option<char>,
+        Option<f32>,
+        Option<char>,
+        Option<&'a [u8]>,
+    ),
+) -> Result<AamData<'a>, NmeaSentenceError<'a>> {
+    Ok(AamData {
+        arrival_circle: translate_option!(sentence.0, ArrivalStatus),
+        perpendicular_passed: translate_option!(sentence.1, ArrivalStatus),
+        radius: sentence.2,
+        radius_unit: translate_option!(sentence.3, DistanceUnit),
+        waypoint: sentence.4,
+    })
+}
+
+named!(pub (crate) parse_aam<AamData>,
+    map_res!(
+        do_parse!(
+            arrival_circle: opt!(one_of!("AV")) >>
+            char!(',') >>
+            perpendicular_passed: opt!(one_of!("AV")) >>
+            char!(',') >>
+            radius: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            radius_unit: opt!(one_of!("NK")) >>
+            char!(',') >>
+            waypoint: opt!(is_not!("*")) >>
+            char!('*') >>
+            (arrival_circle, perpendicular_passed, radius, radius_unit, waypoint)
+        ),
+        build_aam
+    )
+);
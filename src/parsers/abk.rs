@@ -0,0 +1,40 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_abk<'a>(
+    sentence: (
+        Option<u32>,
+        Option<char>,
+        Option<u8>,
+        Option<u8>,
+        Option<u8>,
+    ),
+) -> Result<AbkData, NmeaSentenceError<'a>> {
+    Ok(AbkData {
+        mmsi: sentence.0,
+        channel: translate_option!(sentence.1, AisChannel),
+        message_id: sentence.2,
+        sequence_number: sentence.3,
+        acknowledgement: translate_option!(sentence.4, AbkAcknowledgement),
+    })
+}
+
+named!(pub (crate) parse_abk<AbkData>,
+    map_res!(
+        do_parse!(
+            mmsi: opt!(map_res!(take_until!(","), parse_num::<u32>)) >>
+            char!(',') >>
+            channel: opt!(one_of!("AB")) >>
+            char!(',') >>
+            message_id: opt!(map_res!(take_until!(","), parse_num::<u8>)) >>
+            char!(',') >>
+            sequence_number: opt!(map_res!(take_until!(","), parse_num::<u8>)) >>
+            char!(',') >>
+            acknowledgement: opt!(map_res!(take_until!("*"), parse_num::<u8>)) >>
+            char!('*') >>
+            (mmsi, channel, message_id, sequence_number, acknowledgement)
+        ),
+        build_abk
+    )
+);
@@ -0,0 +1,10 @@
+use super::utils::*;
+use crate::parse::*;
+
+named!(pub (crate) parse_ack<AckData>,
+    do_parse!(
+        alarm_id: opt!(map_res!(take_until!("*"), parse_num::<u16>)) >>
+        char!('*') >>
+        (AckData { alarm_id })
+    )
+);
@@ -0,0 +1,75 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_alm<'a>(
+    sentence: (
+        (Option<u16>, Option<u16>, Option<u8>, Option<u16>),
+        (Option<u32>, Option<u32>, Option<u32>, Option<u32>),
+        (Option<u32>, Option<u32>, Option<u32>, Option<u32>),
+        (Option<u32>, Option<u32>, Option<u32>),
+    ),
+) -> Result<AlmData, NmeaSentenceError<'a>> {
+    let (header, orbit, kepler, clock) = sentence;
+    Ok(AlmData {
+        number_of_sentences: header.0,
+        sentence_num: header.1,
+        sat_id: header.2,
+        week: header.3,
+        health: orbit.0.map(|raw| raw as u8),
+        eccentricity: orbit.1.map(|raw| raw as u16),
+        reference_time: orbit.2.map(|raw| raw as u8),
+        inclination_offset: orbit.3.map(|raw| sign_extend(raw, 16) as i16),
+        rate_of_right_ascension: kepler.0.map(|raw| sign_extend(raw, 16) as i16),
+        sqrt_semi_major_axis: kepler.1,
+        argument_of_perigee: kepler.2.map(|raw| sign_extend(raw, 24)),
+        longitude_of_ascending_node: kepler.3.map(|raw| sign_extend(raw, 24)),
+        mean_anomaly: clock.0.map(|raw| sign_extend(raw, 24)),
+        clock_bias: clock.1.map(|raw| sign_extend(raw, 11) as i16),
+        clock_drift: clock.2.map(|raw| sign_extend(raw, 11) as i16),
+    })
+}
+
+named!(pub (crate) parse_alm<AlmData>,
+    map_res!(
+        do_parse!(
+            number_of_sentences: opt!(map_res!(take_until!(","), parse_num::<u16>)) >>
+            char!(',') >>
+            sentence_num: opt!(map_res!(take_until!(","), parse_num::<u16>)) >>
+            char!(',') >>
+            sat_id: opt!(map_res!(take_until!(","), parse_num::<u8>)) >>
+            char!(',') >>
+            week: opt!(map_res!(take_until!(","), parse_num::<u16>)) >>
+            char!(',') >>
+            health: opt!(map_res!(take_until!(","), parse_hex_num)) >>
+            char!(',') >>
+            eccentricity: opt!(map_res!(take_until!(","), parse_hex_num)) >>
+            char!(',') >>
+            reference_time: opt!(map_res!(take_until!(","), parse_hex_num)) >>
+            char!(',') >>
+            inclination_offset: opt!(map_res!(take_until!(","), parse_hex_num)) >>
+            char!(',') >>
+            rate_of_right_ascension: opt!(map_res!(take_until!(","), parse_hex_num)) >>
+            char!(',') >>
+            sqrt_semi_major_axis: opt!(map_res!(take_until!(","), parse_hex_num)) >>
+            char!(',') >>
+            argument_of_perigee: opt!(map_res!(take_until!(","), parse_hex_num)) >>
+            char!(',') >>
+            longitude_of_ascending_node: opt!(map_res!(take_until!(","), parse_hex_num)) >>
+            char!(',') >>
+            mean_anomaly: opt!(map_res!(take_until!(","), parse_hex_num)) >>
+            char!(',') >>
+            clock_bias: opt!(map_res!(take_until!(","), parse_hex_num)) >>
+            char!(',') >>
+            clock_drift: opt!(map_res!(is_not!("*"), parse_hex_num)) >>
+            char!('*') >>
+            (
+                (number_of_sentences, sentence_num, sat_id, week),
+                (health, eccentricity, reference_time, inclination_offset),
+                (rate_of_right_ascension, sqrt_semi_major_axis, argument_of_perigee, longitude_of_ascending_node),
+                (mean_anomaly, clock_bias, clock_drift)
+            )
+        ),
+        build_alm
+    )
+);
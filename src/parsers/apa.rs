@@ -0,0 +1,88 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_apa<'a>(
+    sentence: (
+        Option<char>,
+        Option<char>,
+        Option<f32>,
+        Option<char>,
+        Option<char>,
+        Option<char>,
+        Option<char>,
+        Option<f32>,
+        Option<char>,
+        Option<&'a [u8]>,
+    ),
+) -> Result<ApaData<'a>, NmeaSentenceError<'a>> {
+    Ok(ApaData {
+        status: translate_option!(sentence.0, DataValidity),
+        cycle_lock_status: translate_option!(sentence.1, DataValidity),
+        cross_track_error: sentence.2,
+        steer_direction: translate_option!(sentence.3, SteerDirection),
+        cross_track_unit: translate_option!(sentence.4, DistanceUnit),
+        arrival_circle: translate_option!(sentence.5, ArrivalStatus),
+        perpendicular_passed: translate_option!(sentence.6, ArrivalStatus),
+        bearing_origin_to_dest: sentence.7,
+        bearing_origin_to_dest_reference: translate_option!(sentence.8, BearingReference),
+        dest_waypoint: sentence.9,
+    })
+}
+
+named!(pub (crate) parse_apa<ApaData>,
+    map_res!(
+        do_parse!(
+            status: opt!(one_of!("AV")) >>
+            char!(',') >>
+            cycle_lock_status: opt!(one_of!("AV")) >>
+            char!(',') >>
+            cross_track_error: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            steer_direction: opt!(one_of!("LR")) >>
+            char!(',') >>
+            cross_track_unit: opt!(one_of!("NK")) >>
+            char!(',') >>
+            arrival_circle: opt!(one_of!("AV")) >>
+            char!(',') >>
+            perpendicular_passed: opt!(one_of!("AV")) >>
+            char!(',') >>
+            bearing_origin_to_dest: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            bearing_origin_to_dest_reference: opt!(one_of!("MT")) >>
+            char!(',') >>
+            dest_waypoint: opt!(is_not!("*")) >>
+            char!('*') >>
+            (status, cycle_lock_status, cross_track_error, steer_direction, cross_track_unit, arrival_circle, perpendicular_passed, bearing_origin_to_dest, bearing_origin_to_dest_reference, dest_waypoint)
+        ),
+        build_apa
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{
+        ApaData, ArrivalStatus, BearingReference, DataValidity, DistanceUnit, SentenceData,
+        SteerDirection,
+    };
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_autopilot_data() {
+        assert_eq!(
+            parse_nmea_sentence(b"$GPAPA,A,A,0.10,R,N,V,V,011,M,DEST*3F\r\n"),
+            Ok(SentenceData::APA(ApaData {
+                status: Some(DataValidity::DataValid),
+                cycle_lock_status: Some(DataValidity::DataValid),
+                cross_track_error: Some(0.10),
+                steer_direction: Some(SteerDirection::Right),
+                cross_track_unit: Some(DistanceUnit::NauticalMiles),
+                arrival_circle: Some(ArrivalStatus::NotArrived),
+                perpendicular_passed: Some(ArrivalStatus::NotArrived),
+                bearing_origin_to_dest: Some(11.0),
+                bearing_origin_to_dest_reference: Some(BearingReference::Magnetic),
+                dest_waypoint: Some(b"DEST"),
+            }))
+        );
+    }
+}
@@ -0,0 +1,135 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_apb<'a>(
+    sentence: (
+        (
+            Option<char>,
+            Option<char>,
+            Option<f32>,
+            Option<char>,
+            Option<char>,
+            Option<char>,
+            Option<char>,
+        ),
+        Option<f32>,
+        Option<char>,
+        Option<&'a [u8]>,
+        Option<f32>,
+        Option<char>,
+        Option<f32>,
+        Option<char>,
+        Option<Option<char>>,
+    ),
+) -> Result<ApbData<'a>, NmeaSentenceError<'a>> {
+    let cross_track = sentence.0;
+    Ok(ApbData {
+        status: translate_option!(cross_track.0, DataValidity),
+        cycle_lock_status: translate_option!(cross_track.1, DataValidity),
+        cross_track_error: cross_track.2,
+        steer_direction: translate_option!(cross_track.3, SteerDirection),
+        cross_track_unit: translate_option!(cross_track.4, DistanceUnit),
+        arrival_circle: translate_option!(cross_track.5, ArrivalStatus),
+        perpendicular_passed: translate_option!(cross_track.6, ArrivalStatus),
+        bearing_origin_to_dest: sentence.1,
+        bearing_origin_to_dest_reference: translate_option!(sentence.2, BearingReference),
+        dest_waypoint: sentence.3,
+        bearing_to_dest: sentence.4,
+        bearing_to_dest_reference: translate_option!(sentence.5, BearingReference),
+        heading_to_steer: sentence.6,
+        heading_to_steer_reference: translate_option!(sentence.7, BearingReference),
+        mode: translate_option!(sentence.8.unwrap_or(None), GnsMode),
+    })
+}
+
+named!(pub (crate) parse_apb<ApbData>,
+    map_res!(
+        do_parse!(
+            status: opt!(one_of!("AV")) >>
+            char!(',') >>
+            cycle_lock_status: opt!(one_of!("AV")) >>
+            char!(',') >>
+            cross_track_error: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            steer_direction: opt!(one_of!("LR")) >>
+            char!(',') >>
+            cross_track_unit: opt!(one_of!("NK")) >>
+            char!(',') >>
+            arrival_circle: opt!(one_of!("AV")) >>
+            char!(',') >>
+            perpendicular_passed: opt!(one_of!("AV")) >>
+            char!(',') >>
+            bearing_origin_to_dest: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            bearing_origin_to_dest_reference: opt!(one_of!("MT")) >>
+            char!(',') >>
+            dest_waypoint: opt!(is_not!(",")) >>
+            char!(',') >>
+            bearing_to_dest: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            bearing_to_dest_reference: opt!(one_of!("MT")) >>
+            char!(',') >>
+            heading_to_steer: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            heading_to_steer_reference: opt!(one_of!("MT")) >>
+            mode: opt!(complete!(do_parse!(
+                char!(',') >>
+                mode: opt!(one_of!("NADPRFEMS")) >>
+                (mode)
+            ))) >>
+            char!('*') >>
+            (
+                (status, cycle_lock_status, cross_track_error, steer_direction, cross_track_unit, arrival_circle, perpendicular_passed),
+                bearing_origin_to_dest, bearing_origin_to_dest_reference, dest_waypoint, bearing_to_dest, bearing_to_dest_reference, heading_to_steer, heading_to_steer_reference, mode
+            )
+        ),
+        build_apb
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{
+        ApbData, ArrivalStatus, BearingReference, DataValidity, DistanceUnit, GnsMode,
+        SentenceData, SteerDirection,
+    };
+    use crate::parse_nmea_sentence;
+
+    const APB: ApbData = ApbData {
+        status: Some(DataValidity::DataValid),
+        cycle_lock_status: Some(DataValidity::DataValid),
+        cross_track_error: Some(0.10),
+        steer_direction: Some(SteerDirection::Right),
+        cross_track_unit: Some(DistanceUnit::NauticalMiles),
+        arrival_circle: Some(ArrivalStatus::NotArrived),
+        perpendicular_passed: Some(ArrivalStatus::NotArrived),
+        bearing_origin_to_dest: Some(11.0),
+        bearing_origin_to_dest_reference: Some(BearingReference::Magnetic),
+        dest_waypoint: Some(b"DEST"),
+        bearing_to_dest: Some(11.0),
+        bearing_to_dest_reference: Some(BearingReference::Magnetic),
+        heading_to_steer: Some(11.0),
+        heading_to_steer_reference: Some(BearingReference::Magnetic),
+        mode: None,
+    };
+
+    #[test]
+    fn parses_autopilot_data() {
+        assert_eq!(
+            parse_nmea_sentence(b"$GPAPB,A,A,0.10,R,N,V,V,011,M,DEST,011,M,011,M*3C\r\n"),
+            Ok(SentenceData::APB(APB))
+        );
+    }
+
+    #[test]
+    fn parses_the_mode_of_nmea_2_3() {
+        assert_eq!(
+            parse_nmea_sentence(b"$GPAPB,A,A,0.10,R,N,V,V,011,M,DEST,011,M,011,M,A*51\r\n"),
+            Ok(SentenceData::APB(ApbData {
+                mode: Some(GnsMode::AutonomousFix),
+                ..APB
+            }))
+        );
+    }
+}
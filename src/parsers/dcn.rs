@@ -0,0 +1,81 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+named!(
+    parse_decca_line<(Option<&[u8]>, Option<f32>, Option<char>)>,
+    do_parse!(
+        zone: opt!(is_not!(",")) >>
+        char!(',') >>
+        line_of_position: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        master_line_status: opt!(one_of!("AV")) >>
+        (zone, line_of_position, master_line_status)
+    )
+);
+
+fn build_decca_line<'a>(
+    line: (Option<&'a [u8]>, Option<f32>, Option<char>),
+    in_use: Option<char>,
+) -> Result<DeccaLine<'a>, NmeaSentenceError<'a>> {
+    Ok(DeccaLine {
+        zone: line.0,
+        line_of_position: line.1,
+        master_line_status: translate_option!(line.2, DataValidity),
+        in_use: translate_option!(in_use, DataValidity),
+    })
+}
+
+fn build_dcn<'a>(
+    sentence: (
+        Option<u8>,
+        [(Option<&'a [u8]>, Option<f32>, Option<char>); 3],
+        [Option<char>; 3],
+        Option<f32>,
+        Option<u8>,
+    ),
+) -> Result<DcnData<'a>, NmeaSentenceError<'a>> {
+    let [red, green, purple] = sentence.1;
+    let [red_in_use, green_in_use, purple_in_use] = sentence.2;
+    Ok(DcnData {
+        chain_id: sentence.0,
+        red: build_decca_line(red, red_in_use)?,
+        green: build_decca_line(green, green_in_use)?,
+        purple: build_decca_line(purple, purple_in_use)?,
+        position_uncertainty: sentence.3,
+        fix_basis: translate_option!(sentence.4, DeccaFixBasis),
+    })
+}
+
+named!(pub (crate) parse_dcn<DcnData>,
+    map_res!(
+        do_parse!(
+            chain_id: opt!(map_res!(take_until!(","), parse_num::<u8>)) >>
+            char!(',') >>
+            red: parse_decca_line >>
+            char!(',') >>
+            green: parse_decca_line >>
+            char!(',') >>
+            purple: parse_decca_line >>
+            char!(',') >>
+            red_in_use: opt!(one_of!("AV")) >>
+            char!(',') >>
+            green_in_use: opt!(one_of!("AV")) >>
+            char!(',') >>
+            purple_in_use: opt!(one_of!("AV")) >>
+            char!(',') >>
+            position_uncertainty: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",N,") >>
+            fix_basis: opt!(map_res!(take_until!("*"), parse_num::<u8>)) >>
+            char!('*') >>
+            (
+                chain_id,
+                [red, green, purple],
+                [red_in_use, green_in_use, purple_in_use],
+                position_uncertainty,
+                fix_basis
+            )
+        ),
+        build_dcn
+    )
+);
@@ -0,0 +1,101 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_depth<'a>(
+    sentence: (
+        Option<f32>,
+        Option<char>,
+        Option<f32>,
+        Option<char>,
+        Option<f32>,
+        Option<char>,
+    ),
+) -> Result<DepthData, NmeaSentenceError<'a>> {
+    let mut data = DepthData {
+        feet: None,
+        meters: None,
+        fathoms: None,
+    };
+
+    for (depth, unit) in &[
+        (sentence.0, sentence.1),
+        (sentence.2, sentence.3),
+        (sentence.4, sentence.5),
+    ] {
+        if let (Some(depth), Some(unit)) = (depth, unit) {
+            match DepthUnit::try_from(*unit)? {
+                DepthUnit::Feet => data.feet = Some(*depth),
+                DepthUnit::Meters => data.meters = Some(*depth),
+                DepthUnit::Fathoms => data.fathoms = Some(*depth),
+            }
+        }
+    }
+
+    Ok(data)
+}
+
+named!(pub (crate) parse_depth<DepthData>,
+    map_res!(
+        do_parse!(
+            depth1: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            unit1: opt!(one_of!("fMF")) >>
+            char!(',') >>
+            depth2: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            unit2: opt!(one_of!("fMF")) >>
+            char!(',') >>
+            depth3: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            unit3: opt!(one_of!("fMF")) >>
+            char!('*') >>
+            (depth1, unit1, depth2, unit2, depth3, unit3)
+        ),
+        build_depth
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{DepthData, SentenceData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_depth_in_every_unit() {
+        let depth = DepthData {
+            feet: Some(7.8),
+            meters: Some(2.4),
+            fathoms: Some(1.3),
+        };
+        assert_eq!(
+            parse_nmea_sentence(b"$SDDBT,7.8,f,2.4,M,1.3,F*0D\r\n"),
+            Ok(SentenceData::DBT(depth))
+        );
+        assert_eq!(
+            parse_nmea_sentence(b"$SDDBK,12.3,f,3.7,M,2.0,F*2F\r\n"),
+            Ok(SentenceData::DBK(DepthData {
+                feet: Some(12.3),
+                meters: Some(3.7),
+                fathoms: Some(2.0),
+            }))
+        );
+    }
+
+    #[test]
+    fn leaves_missing_units_empty() {
+        assert_eq!(
+            parse_nmea_sentence(b"$SDDBS,,f,5.2,M,,F*06\r\n"),
+            Ok(SentenceData::DBS(DepthData {
+                feet: None,
+                meters: Some(5.2),
+                fathoms: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_units() {
+        assert!(parse_nmea_sentence(b"$SDDBT,7.8,f,2.4,M,1.3,X*13\r\n").is_err());
+    }
+}
@@ -0,0 +1,69 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_dpt<'a>(
+    sentence: (Option<f32>, Option<f32>, Option<Option<f32>>),
+) -> Result<DptData, NmeaSentenceError<'a>> {
+    Ok(DptData {
+        depth: sentence.0,
+        offset: sentence.1,
+        max_range: sentence.2.unwrap_or(None),
+    })
+}
+
+named!(pub (crate) parse_dpt<DptData>,
+    map_res!(
+        do_parse!(
+            depth: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            offset: opt!(map_res!(is_not!(",*"), parse_num::<f32>)) >>
+            max_range: opt!(complete!(do_parse!(
+                char!(',') >>
+                max_range: opt!(map_res!(take_until!("*"), parse_num::<f32>)) >>
+                (max_range)
+            ))) >>
+            char!('*') >>
+            (depth, offset, max_range)
+        ),
+        build_dpt
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{DptData, SentenceData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_depth_with_and_without_max_range() {
+        assert_eq!(
+            parse_nmea_sentence(b"$SDDPT,2.4,-0.5*79\r\n"),
+            Ok(SentenceData::DPT(DptData {
+                depth: Some(2.4),
+                offset: Some(-0.5),
+                max_range: None,
+            }))
+        );
+        assert_eq!(
+            parse_nmea_sentence(b"$SDDPT,76.1,0.0,100*7A\r\n"),
+            Ok(SentenceData::DPT(DptData {
+                depth: Some(76.1),
+                offset: Some(0.0),
+                max_range: Some(100.0),
+            }))
+        );
+    }
+
+    #[test]
+    fn parses_empty_fields() {
+        assert_eq!(
+            parse_nmea_sentence(b"$SDDPT,,,*7B\r\n"),
+            Ok(SentenceData::DPT(DptData {
+                depth: None,
+                offset: None,
+                max_range: None,
+            }))
+        );
+    }
+}
@@ -0,0 +1,114 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_dtm<'a>(
+    sentence: (
+        Option<&'a [u8]>,
+        Option<&'a [u8]>,
+        Option<f32>,
+        Option<char>,
+        Option<f32>,
+        Option<char>,
+        Option<f32>,
+        Option<&'a [u8]>,
+    ),
+) -> Result<DtmData<'a>, NmeaSentenceError<'a>> {
+    Ok(DtmData {
+        local_datum: translate_option!(sentence.0, Datum),
+        subdivision: sentence.1,
+        lat_offset: sentence.2,
+        lat_offset_direction: translate_option!(sentence.3, LatitudeDirection),
+        lon_offset: sentence.4,
+        lon_offset_direction: translate_option!(sentence.5, LongitudeDirection),
+        alt_offset: sentence.6,
+        reference_datum: translate_option!(sentence.7, Datum),
+    })
+}
+
+named!(pub (crate) parse_dtm<DtmData>,
+    map_res!(
+        do_parse!(
+            local_datum: opt!(is_not!(",")) >>
+            char!(',') >>
+            subdivision: opt!(is_not!(",")) >>
+            char!(',') >>
+            lat_offset: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            lat_offset_direction: opt!(one_of!("NS")) >>
+            char!(',') >>
+            lon_offset: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            lon_offset_direction: opt!(one_of!("EW")) >>
+            char!(',') >>
+            alt_offset: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            reference_datum: opt!(is_not!("*")) >>
+            char!('*') >>
+            (local_datum, subdivision, lat_offset, lat_offset_direction, lon_offset, lon_offset_direction, alt_offset, reference_datum)
+        ),
+        build_dtm
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{
+        Datum, DtmData, GpsPosition, LatitudeDirection, LongitudeDirection, SentenceData,
+    };
+    use crate::parse_nmea_sentence;
+
+    fn dtm(sentence: &[u8]) -> DtmData<'_> {
+        match parse_nmea_sentence(sentence) {
+            Ok(SentenceData::DTM(dtm)) => dtm,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    #[test]
+    fn parses_local_datum_and_offsets() {
+        assert_eq!(
+            dtm(b"$GPDTM,999,CH,30.0,N,15.0,W,-2.5,W84*39\r\n"),
+            DtmData {
+                local_datum: Some(Datum::UserDefined),
+                subdivision: Some(&b"CH"[..]),
+                lat_offset: Some(30.0),
+                lat_offset_direction: Some(LatitudeDirection::North),
+                lon_offset: Some(15.0),
+                lon_offset_direction: Some(LongitudeDirection::West),
+                alt_offset: Some(-2.5),
+                reference_datum: Some(Datum::Wgs84),
+            }
+        );
+
+        let data = dtm(b"$GPDTM,W84,,,,,,,W84*4A\r\n");
+        assert_eq!(data.local_datum, Some(Datum::Wgs84));
+        assert_eq!(data.subdivision, None);
+        assert_eq!(data.lat_offset, None);
+    }
+
+    #[test]
+    fn removes_the_offsets_from_positions() {
+        let position = GpsPosition {
+            lat: 10.0,
+            lat_dir: LatitudeDirection::North,
+            lon: 0.125,
+            lon_dir: LongitudeDirection::West,
+        };
+
+        // 30' north and 15' west, the longitude ends up on the other side of Greenwich
+        assert_eq!(
+            dtm(b"$GPDTM,999,CH,30.0,N,15.0,W,-2.5,W84*39\r\n").to_reference_datum(position),
+            GpsPosition {
+                lat: 9.5,
+                lat_dir: LatitudeDirection::North,
+                lon: 0.125,
+                lon_dir: LongitudeDirection::East,
+            }
+        );
+        assert_eq!(
+            dtm(b"$GPDTM,W84,,,,,,,W84*4A\r\n").to_reference_datum(position),
+            position
+        );
+    }
+}
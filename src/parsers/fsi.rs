@@ -0,0 +1,31 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_fsi<'a>(
+    sentence: (Option<u32>, Option<u32>, Option<char>, Option<u8>),
+) -> Result<FsiData, NmeaSentenceError<'a>> {
+    Ok(FsiData {
+        transmit_frequency: sentence.0,
+        receive_frequency: sentence.1,
+        mode: translate_option!(sentence.2, FrequencyMode),
+        power_level: sentence.3,
+    })
+}
+
+named!(pub (crate) parse_fsi<FsiData>,
+    map_res!(
+        do_parse!(
+            transmit_frequency: opt!(map_res!(take_until!(","), parse_num::<u32>)) >>
+            char!(',') >>
+            receive_frequency: opt!(map_res!(take_until!(","), parse_num::<u32>)) >>
+            char!(',') >>
+            mode: opt!(one_of!("demoqstwx{|")) >>
+            char!(',') >>
+            power_level: opt!(map_res!(take_until!("*"), parse_num::<u8>)) >>
+            char!('*') >>
+            (transmit_frequency, receive_frequency, mode, power_level)
+        ),
+        build_fsi
+    )
+);
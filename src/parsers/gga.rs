@@ -54,3 +54,23 @@ named!(pub (crate) parse_gga<GgaData>,
         build_gga
     )
 );
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::SentenceData;
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_empty_geoid_separation() {
+        let data = parse_nmea_sentence(
+            b"$GPGGA,092750.000,5321.6802,N,00630.3372,W,1,8,1.03,61.7,M,,M,,*6A\r\n",
+        );
+        match data {
+            Ok(SentenceData::GGA(gga)) => {
+                assert_eq!(gga.altitude, Some(61.7));
+                assert_eq!(gga.geoid_altitude, None);
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+}
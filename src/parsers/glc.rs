@@ -0,0 +1,75 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+named!(
+    parse_glc_td<Option<LoranTd>>,
+    map_res!(
+        do_parse!(
+            microseconds: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            status: opt!(one_of!("ABCS")) >>
+            (microseconds, status)
+        ),
+        |td: (Option<f32>, Option<char>)| -> Result<Option<LoranTd>, NmeaSentenceError> {
+            Ok(loran_td(td.0, translate_option!(td.1, LoranStatus)))
+        }
+    )
+);
+
+named!(pub (crate) parse_glc<GlcData>,
+    do_parse!(
+        gri: opt!(map_res!(take_until!(","), parse_num::<u16>)) >>
+        char!(',') >>
+        master: parse_glc_td >>
+        char!(',') >>
+        td1: parse_glc_td >>
+        char!(',') >>
+        td2: parse_glc_td >>
+        char!(',') >>
+        td3: parse_glc_td >>
+        char!(',') >>
+        td4: parse_glc_td >>
+        char!(',') >>
+        td5: parse_glc_td >>
+        char!('*') >>
+        (GlcData {
+            gri,
+            master,
+            secondaries: [td1, td2, td3, td4, td5],
+        })
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{GlcData, LoranStatus, LoranTd, SentenceData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_times_of_arrival_with_their_status() {
+        assert_eq!(
+            parse_nmea_sentence(b"$LCGLC,9960,41623.52,A,13028.41,A,26891.34,B,,,,,,*3A\r\n"),
+            Ok(SentenceData::GLC(GlcData {
+                gri: Some(9960),
+                master: Some(LoranTd {
+                    microseconds: 41623.52,
+                    status: Some(LoranStatus::Valid),
+                }),
+                secondaries: [
+                    Some(LoranTd {
+                        microseconds: 13028.41,
+                        status: Some(LoranStatus::Valid),
+                    }),
+                    Some(LoranTd {
+                        microseconds: 26891.34,
+                        status: Some(LoranStatus::BlinkWarning),
+                    }),
+                    None,
+                    None,
+                    None,
+                ],
+            }))
+        );
+    }
+}
@@ -30,7 +30,7 @@ fn build_gns<'a>(
     Ok(GnsData {
         time: sentence.0,
         position: sentence.1,
-        mode: mode,
+        mode,
         sats_in_use: sentence.3,
         hdop: sentence.4,
         orthometric_height: sentence.5,
@@ -0,0 +1,146 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_grs<'a>(
+    sentence: (
+        Option<GpsTime>,
+        Option<u8>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+    ),
+) -> Result<GrsData, NmeaSentenceError<'a>> {
+    Ok(GrsData {
+        time: sentence.0,
+        mode: translate_option!(sentence.1, GrsMode),
+        residuals: [
+            sentence.2,
+            sentence.3,
+            sentence.4,
+            sentence.5,
+            sentence.6,
+            sentence.7,
+            sentence.8,
+            sentence.9,
+            sentence.10,
+            sentence.11,
+            sentence.12,
+            sentence.13,
+        ],
+    })
+}
+
+named!(
+    parse_residual<Option<f32>>,
+    do_parse!(
+        residual: opt!(map_res!(is_not!(",*"), parse_num::<f32>)) >>
+        opt!(char!(',')) >>
+        (residual)
+    )
+);
+
+named!(pub (crate) parse_grs<GrsData>,
+    map_res!(
+        do_parse!(
+            time: opt!(complete!(parse_utc_stamp)) >>
+            char!(',') >>
+            mode: opt!(map_res!(take_until!(","), parse_num::<u8>)) >>
+            char!(',') >>
+            residual1: complete!(parse_residual) >>
+            residual2: complete!(parse_residual) >>
+            residual3: complete!(parse_residual) >>
+            residual4: complete!(parse_residual) >>
+            residual5: complete!(parse_residual) >>
+            residual6: complete!(parse_residual) >>
+            residual7: complete!(parse_residual) >>
+            residual8: complete!(parse_residual) >>
+            residual9: complete!(parse_residual) >>
+            residual10: complete!(parse_residual) >>
+            residual11: complete!(parse_residual) >>
+            residual12: complete!(parse_residual) >>
+            // NMEA 4.1 appends the system and signal id
+            opt!(take_until!("*")) >>
+            char!('*') >>
+            (time, mode, residual1, residual2, residual3, residual4, residual5, residual6,
+             residual7, residual8, residual9, residual10, residual11, residual12)
+        ),
+        build_grs
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{GpsTime, GrsData, GrsMode, SentenceData};
+    use crate::parse_nmea_sentence;
+
+    const RESIDUALS: [Option<f32>; 12] = [
+        Some(-0.8),
+        Some(-0.2),
+        Some(-0.1),
+        Some(-0.2),
+        Some(0.8),
+        Some(0.6),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    ];
+
+    #[test]
+    fn parses_range_residuals() {
+        assert_eq!(
+            parse_nmea_sentence(b"$GPGRS,220320.0,0,-0.8,-0.2,-0.1,-0.2,0.8,0.6,,,,,,*79\r\n"),
+            Ok(SentenceData::GRS(GrsData {
+                time: Some(GpsTime {
+                    hour: 22,
+                    minute: 3,
+                    second: 20.0,
+                }),
+                mode: Some(GrsMode::UsedInPosition),
+                residuals: RESIDUALS,
+            }))
+        );
+    }
+
+    #[test]
+    fn ignores_the_system_and_signal_id() {
+        match parse_nmea_sentence(b"$GNGRS,220320.0,1,-0.8,-0.2,-0.1,-0.2,0.8,0.6,,,,,,,1,1*66\r\n")
+        {
+            Ok(SentenceData::GRS(grs)) => {
+                assert_eq!(grs.mode, Some(GrsMode::RecomputedAfterPosition));
+                assert_eq!(grs.residuals, RESIDUALS);
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn pairs_residuals_with_the_satellites_of_a_gsa() {
+        let (grs, gsa) = match (
+            parse_nmea_sentence(b"$GPGRS,220320.0,0,-0.8,-0.2,-0.1,-0.2,0.8,0.6,,,,,,*79\r\n"),
+            parse_nmea_sentence(b"$GPGSA,A,3,04,05,,09,12,,,24,,,,,2.5,1.3,2.1*39\r\n"),
+        ) {
+            (Ok(SentenceData::GRS(grs)), Ok(SentenceData::GSA(gsa))) => (grs, gsa),
+            other => panic!("unexpected result {:?}", other),
+        };
+
+        let mut residuals = grs.residuals_by_satellite(&gsa);
+        assert_eq!(residuals.next(), Some((4, -0.8)));
+        assert_eq!(residuals.next(), Some((5, -0.2)));
+        assert_eq!(residuals.next(), Some((9, -0.2)));
+        assert_eq!(residuals.next(), Some((12, 0.8)));
+        assert_eq!(residuals.next(), None);
+    }
+}
@@ -0,0 +1,96 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_gst<'a>(
+    sentence: (
+        Option<GpsTime>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+    ),
+) -> Result<GstData, NmeaSentenceError<'a>> {
+    Ok(GstData {
+        time: sentence.0,
+        rms: sentence.1,
+        semi_major_error: sentence.2,
+        semi_minor_error: sentence.3,
+        orientation: sentence.4,
+        lat_error: sentence.5,
+        lon_error: sentence.6,
+        alt_error: sentence.7,
+    })
+}
+
+named!(pub (crate) parse_gst<GstData>,
+    map_res!(
+        do_parse!(
+            time: opt!(complete!(parse_utc_stamp)) >>
+            char!(',') >>
+            rms: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            semi_major_error: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            semi_minor_error: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            orientation: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            lat_error: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            lon_error: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            alt_error: opt!(map_res!(take_until!("*"), parse_num::<f32>)) >>
+            char!('*') >>
+            (time, rms, semi_major_error, semi_minor_error, orientation, lat_error, lon_error, alt_error)
+        ),
+        build_gst
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{GpsTime, GstData, SentenceData};
+    use crate::parse_nmea_sentence;
+
+    const TIME: Option<GpsTime> = Some(GpsTime {
+        hour: 17,
+        minute: 28,
+        second: 14.0,
+    });
+
+    #[test]
+    fn parses_error_statistics() {
+        assert_eq!(
+            parse_nmea_sentence(
+                b"$GPGST,172814.0,0.006,0.023,0.020,273.6,0.023,0.020,0.031*6A\r\n"
+            ),
+            Ok(SentenceData::GST(GstData {
+                time: TIME,
+                rms: Some(0.006),
+                semi_major_error: Some(0.023),
+                semi_minor_error: Some(0.020),
+                orientation: Some(273.6),
+                lat_error: Some(0.023),
+                lon_error: Some(0.020),
+                alt_error: Some(0.031),
+            }))
+        );
+        assert_eq!(
+            parse_nmea_sentence(b"$GPGST,172814.0,,,,,,,*40\r\n"),
+            Ok(SentenceData::GST(GstData {
+                time: TIME,
+                rms: None,
+                semi_major_error: None,
+                semi_minor_error: None,
+                orientation: None,
+                lat_error: None,
+                lon_error: None,
+                alt_error: None,
+            }))
+        );
+    }
+}
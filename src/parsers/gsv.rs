@@ -1,7 +1,6 @@
 use super::utils::*;
 use crate::errors::NmeaSentenceError;
 use crate::parse::*;
-use nom::alt;
 
 named!(parse_gsv_sat<GsvSatellite>,
     map_res!(
@@ -12,7 +11,7 @@ named!(parse_gsv_sat<GsvSatellite>,
             char!(',') >>
             true_azimuth: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
             char!(',') >>
-            snr: opt!(alt!(map_res!(take_until!(","), parse_num::<u8>) | map_res!(take_until!(","), parse_num::<u8>))) >>
+            snr: opt!(map_res!(is_not!(",*"), parse_num::<u8>)) >>
             one_of!(",*") >>
             (sat_id, elevation, true_azimuth, snr)
         ),
@@ -64,3 +63,28 @@ named!(pub (crate) parse_gsv<GsvData>,
         build_gsv
     )
 );
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{GsvSatellite, SentenceData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_snr_of_last_satellite() {
+        let data = parse_nmea_sentence(
+            b"$GPGSV,3,1,11,03,03,111,00,04,15,270,00,06,01,010,00,13,06,292,43*73\r\n",
+        );
+        match data {
+            Ok(SentenceData::GSV(gsv)) => assert_eq!(
+                gsv.sats_info[3],
+                Some(GsvSatellite {
+                    sat_id: Some(13),
+                    elevation: Some(6.0),
+                    true_azimuth: Some(292.0),
+                    snr: Some(43),
+                })
+            ),
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+}
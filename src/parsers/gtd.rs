@@ -0,0 +1,59 @@
+use super::utils::*;
+use crate::parse::*;
+
+named!(
+    parse_gtd_td<Option<f32>>,
+    opt!(map_res!(is_not!(",*"), parse_num::<f32>))
+);
+
+named!(pub (crate) parse_gtd<GtdData>,
+    do_parse!(
+        td1: parse_gtd_td >>
+        char!(',') >>
+        td2: parse_gtd_td >>
+        char!(',') >>
+        td3: parse_gtd_td >>
+        char!(',') >>
+        td4: parse_gtd_td >>
+        char!(',') >>
+        td5: parse_gtd_td >>
+        char!('*') >>
+        (GtdData {
+            time_differences: [
+                loran_td(td1, None),
+                loran_td(td2, None),
+                loran_td(td3, None),
+                loran_td(td4, None),
+                loran_td(td5, None),
+            ],
+        })
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{GtdData, LoranTd, SentenceData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_time_differences() {
+        assert_eq!(
+            parse_nmea_sentence(b"$LCGTD,13028.41,26891.34,,,*7A\r\n"),
+            Ok(SentenceData::GTD(GtdData {
+                time_differences: [
+                    Some(LoranTd {
+                        microseconds: 13028.41,
+                        status: None,
+                    }),
+                    Some(LoranTd {
+                        microseconds: 26891.34,
+                        status: None,
+                    }),
+                    None,
+                    None,
+                    None,
+                ],
+            }))
+        );
+    }
+}
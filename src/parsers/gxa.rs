@@ -0,0 +1,21 @@
+use super::utils::*;
+use crate::parse::*;
+
+named!(pub (crate) parse_gxa<GxaData>,
+    do_parse!(
+        time: opt!(complete!(parse_utc_stamp)) >>
+        char!(',') >>
+        position: parse_optional_position >>
+        char!(',') >>
+        waypoint: opt!(is_not!(",")) >>
+        char!(',') >>
+        sat_id: opt!(map_res!(take_until!("*"), parse_num::<u16>)) >>
+        char!('*') >>
+        (GxaData {
+            time,
+            position,
+            waypoint,
+            sat_id,
+        })
+    )
+);
@@ -0,0 +1,102 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_hdg<'a>(
+    sentence: (
+        Option<f32>,
+        Option<f32>,
+        Option<char>,
+        Option<f32>,
+        Option<char>,
+    ),
+) -> Result<HdgData, NmeaSentenceError<'a>> {
+    Ok(HdgData {
+        heading_magnetic_sensor: sentence.0,
+        deviation: sentence.1,
+        deviation_direction: translate_option!(sentence.2, LongitudeDirection),
+        variation: sentence.3,
+        variation_direction: translate_option!(sentence.4, LongitudeDirection),
+    })
+}
+
+named!(pub (crate) parse_hdg<HdgData>,
+    map_res!(
+        do_parse!(
+            heading_magnetic_sensor: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            deviation: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            deviation_direction: opt!(one_of!("EW")) >>
+            char!(',') >>
+            variation: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            variation_direction: opt!(one_of!("EW")) >>
+            char!('*') >>
+            (heading_magnetic_sensor, deviation, deviation_direction, variation, variation_direction)
+        ),
+        build_hdg
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{HdgData, LongitudeDirection, SentenceData};
+    use crate::parse_nmea_sentence;
+
+    fn hdg(sentence: &[u8]) -> HdgData {
+        match parse_nmea_sentence(sentence) {
+            Ok(SentenceData::HDG(hdg)) => hdg,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    #[test]
+    fn parses_deviation_and_variation() {
+        assert_eq!(
+            hdg(b"$HCHDG,101.5,2.0,E,3.25,W*63\r\n"),
+            HdgData {
+                heading_magnetic_sensor: Some(101.5),
+                deviation: Some(2.0),
+                deviation_direction: Some(LongitudeDirection::East),
+                variation: Some(3.25),
+                variation_direction: Some(LongitudeDirection::West),
+            }
+        );
+    }
+
+    #[test]
+    fn adds_easterly_and_subtracts_westerly_corrections() {
+        let data = hdg(b"$HCHDG,101.5,2.0,E,3.25,W*63\r\n");
+        assert_eq!(data.heading_magnetic(), Some(103.5));
+        assert_eq!(data.heading_true(), Some(100.25));
+    }
+
+    #[test]
+    fn wraps_headings_around_north() {
+        let data = hdg(b"$HCHDG,359.5,1.0,E,0.25,E*7E\r\n");
+        assert_eq!(data.heading_magnetic(), Some(0.5));
+        assert_eq!(data.heading_true(), Some(0.75));
+
+        let data = hdg(b"$HCHDG,0.5,1.0,W,,*3F\r\n");
+        assert_eq!(data.heading_magnetic(), Some(359.5));
+    }
+
+    #[test]
+    fn handles_missing_fields() {
+        // Without a variation the true heading is unknown
+        let data = hdg(b"$HCHDG,0.5,1.0,W,,*3F\r\n");
+        assert_eq!(data.variation, None);
+        assert_eq!(data.heading_true(), None);
+
+        // A missing deviation is treated as zero
+        let data = hdg(b"$HCHDG,98.25,,,,*44\r\n");
+        assert_eq!(data.deviation_direction, None);
+        assert_eq!(data.heading_magnetic(), Some(98.25));
+
+        let data = hdg(b"$HCHDG,,,,,*6C\r\n");
+        assert_eq!(data.heading_magnetic_sensor, None);
+        assert_eq!(data.heading_magnetic(), None);
+        assert_eq!(data.heading_true(), None);
+    }
+}
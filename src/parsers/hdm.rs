@@ -0,0 +1,40 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+named!(pub (crate) parse_hdm<HdmData>,
+    map_res!(
+        do_parse!(
+            heading_magnetic: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",M*") >>
+            (heading_magnetic)
+        ),
+        | sentence: Option<f32> | -> Result<HdmData, NmeaSentenceError> {
+            Ok(HdmData {
+                heading_magnetic: sentence,
+            })
+        }
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{HdmData, SentenceData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_magnetic_heading() {
+        assert_eq!(
+            parse_nmea_sentence(b"$HCHDM,238.5,M*25\r\n"),
+            Ok(SentenceData::HDM(HdmData {
+                heading_magnetic: Some(238.5),
+            }))
+        );
+        assert_eq!(
+            parse_nmea_sentence(b"$HCHDM,,M*07\r\n"),
+            Ok(SentenceData::HDM(HdmData {
+                heading_magnetic: None,
+            }))
+        );
+    }
+}
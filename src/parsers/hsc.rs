@@ -0,0 +1,15 @@
+use super::utils::*;
+use crate::parse::*;
+
+named!(pub (crate) parse_hsc<HscData>,
+    do_parse!(
+        heading_true: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        tag!(",T,") >>
+        heading_magnetic: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        tag!(",M*") >>
+        (HscData {
+            heading_true,
+            heading_magnetic,
+        })
+    )
+);
@@ -0,0 +1,74 @@
+use super::utils::*;
+use crate::parse::*;
+
+named!(
+    parse_lcd_signal<LoranSignal>,
+    do_parse!(
+        snr: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        ecd: opt!(map_res!(is_not!(",*"), parse_num::<f32>)) >>
+        (LoranSignal { snr, ecd })
+    )
+);
+
+named!(pub (crate) parse_lcd<LcdData>,
+    do_parse!(
+        gri: opt!(map_res!(take_until!(","), parse_num::<u16>)) >>
+        char!(',') >>
+        master: parse_lcd_signal >>
+        char!(',') >>
+        signal1: parse_lcd_signal >>
+        char!(',') >>
+        signal2: parse_lcd_signal >>
+        char!(',') >>
+        signal3: parse_lcd_signal >>
+        char!(',') >>
+        signal4: parse_lcd_signal >>
+        char!(',') >>
+        signal5: parse_lcd_signal >>
+        char!('*') >>
+        (LcdData {
+            gri,
+            master,
+            secondaries: [signal1, signal2, signal3, signal4, signal5],
+        })
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{LcdData, LoranSignal, SentenceData};
+    use crate::parse_nmea_sentence;
+
+    const NO_SIGNAL: LoranSignal = LoranSignal {
+        snr: None,
+        ecd: None,
+    };
+
+    #[test]
+    fn parses_signal_quality() {
+        assert_eq!(
+            parse_nmea_sentence(b"$LCLCD,9960,025,,030,005,028,-003,,,,,,*7B\r\n"),
+            Ok(SentenceData::LCD(LcdData {
+                gri: Some(9960),
+                master: LoranSignal {
+                    snr: Some(25.0),
+                    ecd: None,
+                },
+                secondaries: [
+                    LoranSignal {
+                        snr: Some(30.0),
+                        ecd: Some(5.0),
+                    },
+                    LoranSignal {
+                        snr: Some(28.0),
+                        ecd: Some(-3.0),
+                    },
+                    NO_SIGNAL,
+                    NO_SIGNAL,
+                    NO_SIGNAL,
+                ],
+            }))
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_mda<'a>(
+    sentence: (
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+    ),
+) -> Result<MdaData, NmeaSentenceError<'a>> {
+    Ok(MdaData {
+        pressure_inches: sentence.0,
+        pressure_bars: sentence.1,
+        air_temperature: sentence.2,
+        water_temperature: sentence.3,
+        relative_humidity: sentence.4,
+        absolute_humidity: sentence.5,
+        dew_point: sentence.6,
+        wind_direction_true: sentence.7,
+        wind_direction_magnetic: sentence.8,
+        wind_speed_knots: sentence.9,
+        wind_speed_mps: sentence.10,
+    })
+}
+
+// Instruments tend to leave out the unit of fields they do not measure,
+// so all units are optional
+named!(pub (crate) parse_mda<MdaData>,
+    map_res!(
+        do_parse!(
+            pressure_inches: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            opt!(char!('I')) >>
+            char!(',') >>
+            pressure_bars: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            opt!(char!('B')) >>
+            char!(',') >>
+            air_temperature: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            opt!(char!('C')) >>
+            char!(',') >>
+            water_temperature: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            opt!(char!('C')) >>
+            char!(',') >>
+            relative_humidity: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            absolute_humidity: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            dew_point: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            opt!(char!('C')) >>
+            char!(',') >>
+            wind_direction_true: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            opt!(char!('T')) >>
+            char!(',') >>
+            wind_direction_magnetic: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            opt!(char!('M')) >>
+            char!(',') >>
+            wind_speed_knots: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            opt!(char!('N')) >>
+            char!(',') >>
+            wind_speed_mps: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            opt!(char!('M')) >>
+            char!('*') >>
+            (pressure_inches, pressure_bars, air_temperature, water_temperature, relative_humidity, absolute_humidity, dew_point, wind_direction_true, wind_direction_magnetic, wind_speed_knots, wind_speed_mps)
+        ),
+        build_mda
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{MdaData, SentenceData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_weather_data() {
+        assert_eq!(
+            parse_nmea_sentence(
+                b"$WIMDA,30.0497,I,1.0176,B,18.9,C,,,43.4,,6.1,C,,T,,M,0.5,N,0.3,M*53\r\n"
+            ),
+            Ok(SentenceData::MDA(MdaData {
+                pressure_inches: Some(30.0497),
+                pressure_bars: Some(1.0176),
+                air_temperature: Some(18.9),
+                water_temperature: None,
+                relative_humidity: Some(43.4),
+                absolute_humidity: None,
+                dew_point: Some(6.1),
+                wind_direction_true: None,
+                wind_direction_magnetic: None,
+                wind_speed_knots: Some(0.5),
+                wind_speed_mps: Some(0.3),
+            }))
+        );
+    }
+
+    #[test]
+    fn accepts_missing_units() {
+        assert_eq!(
+            parse_nmea_sentence(b"$WIMDA,,,,,,,,,,,,,,,,,,,,*56\r\n"),
+            Ok(SentenceData::MDA(MdaData {
+                pressure_inches: None,
+                pressure_bars: None,
+                air_temperature: None,
+                water_temperature: None,
+                relative_humidity: None,
+                absolute_humidity: None,
+                dew_point: None,
+                wind_direction_true: None,
+                wind_direction_magnetic: None,
+                wind_speed_knots: None,
+                wind_speed_mps: None,
+            }))
+        );
+    }
+}
@@ -1,18 +1,72 @@
 #[macro_use]
 mod utils;
+pub(crate) use utils::sign_extend;
+pub(crate) mod aam;
+pub(crate) mod abk;
+pub(crate) mod ack;
+pub(crate) mod alm;
+pub(crate) mod apa;
+pub(crate) mod apb;
 pub(crate) mod bod;
 pub(crate) mod bwc;
+pub(crate) mod dcn;
+pub(crate) mod depth;
+pub(crate) mod dpt;
+pub(crate) mod dtm;
+pub(crate) mod fsi;
 pub(crate) mod gbs;
 pub(crate) mod gga;
+pub(crate) mod glc;
 pub(crate) mod gll;
 pub(crate) mod gns;
+pub(crate) mod grs;
 pub(crate) mod gsa;
+pub(crate) mod gst;
 pub(crate) mod gsv;
+pub(crate) mod gtd;
+pub(crate) mod gxa;
+pub(crate) mod hdg;
+pub(crate) mod hdm;
 pub(crate) mod hdt;
+pub(crate) mod hsc;
+pub(crate) mod lcd;
+pub(crate) mod mda;
+pub(crate) mod msk;
+pub(crate) mod mtw;
+pub(crate) mod mwd;
+pub(crate) mod mwv;
+pub(crate) mod oln;
+pub(crate) mod osd;
+pub(crate) mod pgrm;
+pub(crate) mod pmtk;
+pub(crate) mod pubx;
 pub(crate) mod rma;
 pub(crate) mod rmb;
 pub(crate) mod rmc;
+pub(crate) mod roo;
+pub(crate) mod rot;
+pub(crate) mod rpm;
+pub(crate) mod rsa;
+pub(crate) mod rsd;
+pub(crate) mod rte;
+pub(crate) mod sfi;
 pub(crate) mod stn;
+pub(crate) mod tll;
+pub(crate) mod ttm;
 pub(crate) mod vbw;
+pub(crate) mod vdm;
+pub(crate) mod vdr;
+pub(crate) mod vhw;
+pub(crate) mod vlw;
+pub(crate) mod vpw;
 pub(crate) mod vtg;
+pub(crate) mod vwr;
+pub(crate) mod wcv;
+pub(crate) mod wnc;
 pub(crate) mod wpl;
+pub(crate) mod xdr;
+pub(crate) mod xte;
+pub(crate) mod xtr;
+pub(crate) mod zda;
+pub(crate) mod zfo;
+pub(crate) mod ztg;
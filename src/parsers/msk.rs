@@ -0,0 +1,47 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_msk<'a>(
+    sentence: (
+        Option<f32>,
+        Option<char>,
+        Option<u16>,
+        Option<char>,
+        Option<f32>,
+        Option<Option<u8>>,
+    ),
+) -> Result<MskData, NmeaSentenceError<'a>> {
+    Ok(MskData {
+        frequency: sentence.0,
+        frequency_mode: translate_option!(sentence.1, GsaSelectionMode),
+        bit_rate: sentence.2,
+        bit_rate_mode: translate_option!(sentence.3, GsaSelectionMode),
+        status_interval: sentence.4,
+        channel: sentence.5.unwrap_or(None),
+    })
+}
+
+named!(pub (crate) parse_msk<MskData>,
+    map_res!(
+        do_parse!(
+            frequency: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            frequency_mode: opt!(one_of!("AM")) >>
+            char!(',') >>
+            bit_rate: opt!(map_res!(take_until!(","), parse_num::<u16>)) >>
+            char!(',') >>
+            bit_rate_mode: opt!(one_of!("AM")) >>
+            char!(',') >>
+            status_interval: opt!(map_res!(is_not!(",*"), parse_num::<f32>)) >>
+            channel: opt!(complete!(do_parse!(
+                char!(',') >>
+                channel: opt!(map_res!(take_until!("*"), parse_num::<u8>)) >>
+                (channel)
+            ))) >>
+            char!('*') >>
+            (frequency, frequency_mode, bit_rate, bit_rate_mode, status_interval, channel)
+        ),
+        build_msk
+    )
+);
@@ -0,0 +1,45 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+named!(pub (crate) parse_mtw<MtwData>,
+    map_res!(
+        do_parse!(
+            temperature: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            unit: opt!(one_of!("C")) >>
+            char!('*') >>
+            (temperature, unit)
+        ),
+        | sentence: (Option<f32>, Option<char>)| -> Result<MtwData, NmeaSentenceError> {
+            Ok(MtwData{
+                temperature: sentence.0,
+                unit: translate_option!(sentence.1, TemperatureUnit),
+            })
+        }
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{MtwData, SentenceData, TemperatureUnit};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_water_temperature() {
+        assert_eq!(
+            parse_nmea_sentence(b"$IIMTW,17.9,C*1C\r\n"),
+            Ok(SentenceData::MTW(MtwData {
+                temperature: Some(17.9),
+                unit: Some(TemperatureUnit::Celsius),
+            }))
+        );
+        assert_eq!(
+            parse_nmea_sentence(b"$IIMTW,,*4E\r\n"),
+            Ok(SentenceData::MTW(MtwData {
+                temperature: None,
+                unit: None,
+            }))
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+named!(pub (crate) parse_mwd<MwdData>,
+    map_res!(
+        do_parse!(
+            direction_true: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",T,") >>
+            direction_magnetic: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",M,") >>
+            speed_knots: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",N,") >>
+            speed_mps: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",M*") >>
+            (direction_true, direction_magnetic, speed_knots, speed_mps)
+        ),
+        | sentence: (Option<f32>, Option<f32>, Option<f32>, Option<f32>)| -> Result<MwdData, NmeaSentenceError> {
+            Ok(MwdData{
+                direction_true: sentence.0,
+                direction_magnetic: sentence.1,
+                speed_knots: sentence.2,
+                speed_mps: sentence.3,
+            })
+        }
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{MwdData, SentenceData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_wind_direction_and_speed() {
+        assert_eq!(
+            parse_nmea_sentence(b"$WIMWD,295.0,T,292.4,M,10.5,N,5.4,M*6C\r\n"),
+            Ok(SentenceData::MWD(MwdData {
+                direction_true: Some(295.0),
+                direction_magnetic: Some(292.4),
+                speed_knots: Some(10.5),
+                speed_mps: Some(5.4),
+            }))
+        );
+        assert_eq!(
+            parse_nmea_sentence(b"$WIMWD,,T,,M,,N,,M*5A\r\n"),
+            Ok(SentenceData::MWD(MwdData {
+                direction_true: None,
+                direction_magnetic: None,
+                speed_knots: None,
+                speed_mps: None,
+            }))
+        );
+    }
+}
@@ -0,0 +1,70 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_mwv<'a>(
+    sentence: (
+        Option<f32>,
+        Option<char>,
+        Option<f32>,
+        Option<char>,
+        Option<char>,
+    ),
+) -> Result<MwvData, NmeaSentenceError<'a>> {
+    Ok(MwvData {
+        angle: sentence.0,
+        reference: translate_option!(sentence.1, WindReference),
+        speed: sentence.2,
+        speed_unit: translate_option!(sentence.3, SpeedUnit),
+        status: translate_option!(sentence.4, DataValidity),
+    })
+}
+
+named!(pub (crate) parse_mwv<MwvData>,
+    map_res!(
+        do_parse!(
+            angle: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            reference: opt!(one_of!("RT")) >>
+            char!(',') >>
+            speed: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            speed_unit: opt!(one_of!("KMNS")) >>
+            char!(',') >>
+            status: opt!(one_of!("AV")) >>
+            char!('*') >>
+            (angle, reference, speed, speed_unit, status)
+        ),
+        build_mwv
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{DataValidity, MwvData, SentenceData, SpeedUnit, WindReference};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_wind_speed_and_angle() {
+        assert_eq!(
+            parse_nmea_sentence(b"$WIMWV,214.8,R,0.1,K,A*28\r\n"),
+            Ok(SentenceData::MWV(MwvData {
+                angle: Some(214.8),
+                reference: Some(WindReference::Relative),
+                speed: Some(0.1),
+                speed_unit: Some(SpeedUnit::KilometersPerHour),
+                status: Some(DataValidity::DataValid),
+            }))
+        );
+        assert_eq!(
+            parse_nmea_sentence(b"$WIMWV,,T,,N,V*32\r\n"),
+            Ok(SentenceData::MWV(MwvData {
+                angle: None,
+                reference: Some(WindReference::Theoretical),
+                speed: None,
+                speed_unit: Some(SpeedUnit::Knots),
+                status: Some(DataValidity::DataInvalid),
+            }))
+        );
+    }
+}
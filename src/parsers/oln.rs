@@ -0,0 +1,32 @@
+use super::utils::*;
+use crate::parse::*;
+
+named!(
+    parse_omega_lane<OmegaLane>,
+    do_parse!(
+        pair: opt!(is_not!(",")) >>
+        char!(',') >>
+        lane: opt!(map_res!(take_until!(","), parse_num::<u16>)) >>
+        char!(',') >>
+        centilane: opt!(map_res!(is_not!(",*"), parse_num::<u16>)) >>
+        (OmegaLane {
+            pair,
+            lane,
+            centilane,
+        })
+    )
+);
+
+named!(pub (crate) parse_oln<OlnData>,
+    do_parse!(
+        first: parse_omega_lane >>
+        char!(',') >>
+        second: parse_omega_lane >>
+        char!(',') >>
+        third: parse_omega_lane >>
+        char!('*') >>
+        (OlnData {
+            lanes: [first, second, third],
+        })
+    )
+);
@@ -0,0 +1,94 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_osd<'a>(
+    sentence: (
+        Option<f32>,
+        Option<char>,
+        Option<f32>,
+        Option<char>,
+        Option<f32>,
+        Option<char>,
+        Option<f32>,
+        Option<f32>,
+        Option<char>,
+    ),
+) -> Result<OsdData, NmeaSentenceError<'a>> {
+    Ok(OsdData {
+        heading_true: sentence.0,
+        status: translate_option!(sentence.1, DataValidity),
+        course_true: sentence.2,
+        course_reference: translate_option!(sentence.3, MotionReference),
+        speed: sentence.4,
+        speed_reference: translate_option!(sentence.5, MotionReference),
+        set_true: sentence.6,
+        drift: sentence.7,
+        speed_unit: translate_option!(sentence.8, SpeedUnit),
+    })
+}
+
+named!(pub (crate) parse_osd<OsdData>,
+    map_res!(
+        do_parse!(
+            heading_true: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            status: opt!(one_of!("AV")) >>
+            char!(',') >>
+            course_true: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            course_reference: opt!(one_of!("BMWRP")) >>
+            char!(',') >>
+            speed: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            speed_reference: opt!(one_of!("BMWRP")) >>
+            char!(',') >>
+            set_true: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            drift: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            speed_unit: opt!(one_of!("KNS")) >>
+            char!('*') >>
+            (heading_true, status, course_true, course_reference, speed, speed_reference, set_true, drift, speed_unit)
+        ),
+        build_osd
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{DataValidity, MotionReference, OsdData, SentenceData, SpeedUnit};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_own_ship_data() {
+        assert_eq!(
+            parse_nmea_sentence(b"$INOSD,082.4,A,081.2,B,12.5,B,,,N*61\r\n"),
+            Ok(SentenceData::OSD(OsdData {
+                heading_true: Some(82.4),
+                status: Some(DataValidity::DataValid),
+                course_true: Some(81.2),
+                course_reference: Some(MotionReference::BottomTracking),
+                speed: Some(12.5),
+                speed_reference: Some(MotionReference::BottomTracking),
+                set_true: None,
+                drift: None,
+                speed_unit: Some(SpeedUnit::Knots),
+            }))
+        );
+        assert_eq!(
+            parse_nmea_sentence(b"$INOSD,,V,,,,,,,*25\r\n"),
+            Ok(SentenceData::OSD(OsdData {
+                heading_true: None,
+                status: Some(DataValidity::DataInvalid),
+                course_true: None,
+                course_reference: None,
+                speed: None,
+                speed_reference: None,
+                set_true: None,
+                drift: None,
+                speed_unit: None,
+            }))
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_pgrmz<'a>(
+    sentence: (Option<f32>, Option<char>),
+) -> Result<PgrmData<'a>, NmeaSentenceError<'a>> {
+    Ok(PgrmData::Altitude(PgrmzData {
+        altitude: sentence.0,
+        altitude_source: translate_option!(sentence.1, PgrmzAltitudeSource),
+    }))
+}
+
+named!(pub (crate) parse_pgrme<PgrmData>,
+    do_parse!(
+        horizontal_error: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        opt!(char!('M')) >>
+        char!(',') >>
+        vertical_error: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        opt!(char!('M')) >>
+        char!(',') >>
+        spherical_error: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        opt!(char!('M')) >>
+        char!('*') >>
+        (PgrmData::PositionError(PgrmeData {
+            horizontal_error,
+            vertical_error,
+            spherical_error,
+        }))
+    )
+);
+
+named!(pub (crate) parse_pgrmz<PgrmData>,
+    map_res!(
+        do_parse!(
+            altitude: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            opt!(char!('f')) >>
+            char!(',') >>
+            altitude_source: opt!(one_of!("23")) >>
+            char!('*') >>
+            (altitude, altitude_source)
+        ),
+        build_pgrmz
+    )
+);
+
+named!(pub (crate) parse_pgrmm<PgrmData>,
+    do_parse!(
+        datum: opt!(is_not!("*")) >>
+        char!('*') >>
+        (PgrmData::Datum(PgrmmData { datum }))
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{
+        PgrmData, PgrmeData, PgrmmData, PgrmzAltitudeSource, PgrmzData, SentenceData,
+    };
+    use crate::parse_nmea_sentence;
+    use crate::proprietary::{Proprietary, ProprietaryData};
+
+    fn pgrm(sentence: &[u8]) -> PgrmData<'_> {
+        match parse_nmea_sentence(sentence) {
+            Ok(SentenceData::Proprietary(Proprietary::Parsed(ProprietaryData::PGRM(data)))) => data,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    #[test]
+    fn parses_position_errors() {
+        assert_eq!(
+            pgrm(b"$PGRME,15.0,M,45.0,M,25.0,M*1C\r\n"),
+            PgrmData::PositionError(PgrmeData {
+                horizontal_error: Some(15.0),
+                vertical_error: Some(45.0),
+                spherical_error: Some(25.0),
+            })
+        );
+        assert_eq!(
+            pgrm(b"$PGRME,,M,,M,,M*00\r\n"),
+            PgrmData::PositionError(PgrmeData {
+                horizontal_error: None,
+                vertical_error: None,
+                spherical_error: None,
+            })
+        );
+    }
+
+    #[test]
+    fn parses_altitudes() {
+        assert_eq!(
+            pgrm(b"$PGRMZ,246,f,3*1B\r\n"),
+            PgrmData::Altitude(PgrmzData {
+                altitude: Some(246.0),
+                altitude_source: Some(PgrmzAltitudeSource::GpsAltitude),
+            })
+        );
+        assert_eq!(
+            pgrm(b"$PGRMZ,93,f,2*20\r\n"),
+            PgrmData::Altitude(PgrmzData {
+                altitude: Some(93.0),
+                altitude_source: Some(PgrmzAltitudeSource::UserAltitude),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_map_datums() {
+        assert_eq!(
+            pgrm(b"$PGRMM,WGS 84*06\r\n"),
+            PgrmData::Datum(PgrmmData {
+                datum: Some(&b"WGS 84"[..]),
+            })
+        );
+        assert_eq!(
+            pgrm(b"$PGRMM,*69\r\n"),
+            PgrmData::Datum(PgrmmData { datum: None })
+        );
+    }
+}
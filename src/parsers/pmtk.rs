@@ -0,0 +1,100 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_pmtk_ack<'a>(
+    sentence: (Option<u16>, Option<char>),
+) -> Result<PmtkData<'a>, NmeaSentenceError<'a>> {
+    Ok(PmtkData::Ack(PmtkAckData {
+        command: sentence.0,
+        flag: translate_option!(sentence.1, PmtkAckFlag),
+    }))
+}
+
+named!(pub (crate) parse_pmtk_ack<PmtkData>,
+    map_res!(
+        do_parse!(
+            command: opt!(map_res!(take_until!(","), parse_num::<u16>)) >>
+            char!(',') >>
+            flag: opt!(one_of!("0123")) >>
+            char!('*') >>
+            (command, flag)
+        ),
+        build_pmtk_ack
+    )
+);
+
+named!(pub (crate) parse_pmtk_firmware<PmtkData>,
+    do_parse!(
+        release: opt!(is_not!(",*")) >>
+        opt!(char!(',')) >>
+        build_id: opt!(is_not!(",*")) >>
+        opt!(char!(',')) >>
+        product_model: opt!(is_not!(",*")) >>
+        opt!(char!(',')) >>
+        // Older firmware does not report the SDK version
+        sdk_version: opt!(is_not!(",*")) >>
+        char!('*') >>
+        (PmtkData::Firmware(PmtkFirmwareData {
+            release,
+            build_id,
+            product_model,
+            sdk_version,
+        }))
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{PmtkAckData, PmtkAckFlag, PmtkData, PmtkFirmwareData, SentenceData};
+    use crate::parse_nmea_sentence;
+    use crate::proprietary::{Proprietary, ProprietaryData};
+
+    fn pmtk(sentence: &[u8]) -> PmtkData<'_> {
+        match parse_nmea_sentence(sentence) {
+            Ok(SentenceData::Proprietary(Proprietary::Parsed(ProprietaryData::PMTK(data)))) => data,
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    #[test]
+    fn parses_acknowledgements() {
+        assert_eq!(
+            pmtk(b"$PMTK001,220,3*30\r\n"),
+            PmtkData::Ack(PmtkAckData {
+                command: Some(220),
+                flag: Some(PmtkAckFlag::ActionSucceeded),
+            })
+        );
+        assert_eq!(
+            pmtk(b"$PMTK001,605,0*30\r\n"),
+            PmtkData::Ack(PmtkAckData {
+                command: Some(605),
+                flag: Some(PmtkAckFlag::InvalidCommand),
+            })
+        );
+    }
+
+    #[test]
+    fn parses_firmware_releases() {
+        assert_eq!(
+            pmtk(b"$PMTK705,AXN_2.10_3339_2012072601,5223,PA6H,1.0*6A\r\n"),
+            PmtkData::Firmware(PmtkFirmwareData {
+                release: Some(&b"AXN_2.10_3339_2012072601"[..]),
+                build_id: Some(&b"5223"[..]),
+                product_model: Some(&b"PA6H"[..]),
+                sdk_version: Some(&b"1.0"[..]),
+            })
+        );
+        // Older firmware does not report the SDK version
+        assert_eq!(
+            pmtk(b"$PMTK705,AXN_1.3,2102,ABCD*3D\r\n"),
+            PmtkData::Firmware(PmtkFirmwareData {
+                release: Some(&b"AXN_1.3"[..]),
+                build_id: Some(&b"2102"[..]),
+                product_model: Some(&b"ABCD"[..]),
+                sdk_version: None,
+            })
+        );
+    }
+}
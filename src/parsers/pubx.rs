@@ -0,0 +1,177 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+/// Parses the id, status, azimuth, elevation, C/N0 and lock time fields of a single satellite
+pub(crate) fn parse_satellite(fields: [&[u8]; 6]) -> Result<PubxSatellite, NmeaSentenceError<'_>> {
+    let status = match fields[1] {
+        [status] => PubxSatelliteStatus::try_from(*status as char)?,
+        _ => return Err(NmeaSentenceError::GeneralParsingError),
+    };
+
+    Ok(PubxSatellite {
+        sat_id: parse_num::<u16>(fields[0])?,
+        status,
+        azimuth: parse_optional_num::<u16>(fields[2])?,
+        elevation: parse_optional_num::<i8>(fields[3])?,
+        cno: parse_optional_num::<u8>(fields[4])?,
+        lock_time: parse_optional_num::<u8>(fields[5])?,
+    })
+}
+
+fn parse_optional_num<I: core::str::FromStr>(
+    data: &[u8],
+) -> Result<Option<I>, NmeaSentenceError<'_>> {
+    match data {
+        [] => Ok(None),
+        data => Ok(Some(parse_num::<I>(data)?)),
+    }
+}
+
+fn build_satellites<'a>(
+    sentence: (Option<u8>, &'a [u8]),
+) -> Result<PubxData<'a>, NmeaSentenceError<'a>> {
+    // The list starts with the comma separating it from the satellite count,
+    // some receivers terminate it with one as well
+    let mut satellites = sentence.1;
+    if let [b',', rest @ ..] = satellites {
+        satellites = rest;
+    }
+    if let [rest @ .., b','] = satellites {
+        satellites = rest;
+    }
+
+    if !satellites.is_empty() {
+        let mut fields = satellites.split(|byte| *byte == b',');
+        while let Some(sat_id) = fields.next() {
+            match (
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+                fields.next(),
+            ) {
+                (Some(status), Some(azimuth), Some(elevation), Some(cno), Some(lock_time)) => {
+                    parse_satellite([sat_id, status, azimuth, elevation, cno, lock_time])?;
+                }
+                _ => return Err(NmeaSentenceError::GeneralParsingError),
+            }
+        }
+    }
+
+    Ok(PubxData::Satellites(PubxSatellitesData {
+        number_of_satellites: sentence.0,
+        satellites,
+    }))
+}
+
+named!(
+    parse_pubx_position<PubxData>,
+    do_parse!(
+        tag!("00,") >>
+        time: opt!(complete!(parse_utc_stamp)) >>
+        char!(',') >>
+        position: parse_optional_position >>
+        char!(',') >>
+        altitude: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        navigation_status: opt!(map_res!(take_until!(","), PubxNavigationStatus::try_from)) >>
+        char!(',') >>
+        horizontal_accuracy: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        vertical_accuracy: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        speed_kmh: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        course: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        vertical_velocity: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        age_of_differential: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        hdop: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        vdop: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        tdop: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        sats_used: opt!(map_res!(take_until!(","), parse_num::<u8>)) >>
+        char!(',') >>
+        take_until!(",") >>
+        char!(',') >>
+        dead_reckoning: opt!(one_of!("01")) >>
+        opt!(char!(',')) >>
+        char!('*') >>
+        (PubxData::Position(PubxPositionData {
+            time,
+            position,
+            altitude,
+            navigation_status,
+            horizontal_accuracy,
+            vertical_accuracy,
+            speed_kmh,
+            course,
+            vertical_velocity,
+            age_of_differential,
+            hdop,
+            vdop,
+            tdop,
+            sats_used,
+            dead_reckoning: dead_reckoning == Some('1'),
+        }))
+    )
+);
+
+named!(
+    parse_pubx_satellites<PubxData>,
+    map_res!(
+        do_parse!(
+            tag!("03,") >>
+            number_of_satellites: opt!(map_res!(is_not!(",*"), parse_num::<u8>)) >>
+            satellites: take_until!("*") >>
+            char!('*') >>
+            (number_of_satellites, satellites)
+        ),
+        build_satellites
+    )
+);
+
+named!(
+    parse_pubx_time<PubxData>,
+    do_parse!(
+        tag!("04,") >>
+        time: opt!(complete!(parse_utc_stamp)) >>
+        char!(',') >>
+        date: opt!(complete!(parse_date)) >>
+        char!(',') >>
+        utc_time_of_week: opt!(map_res!(take_until!(","), parse_num::<f64>)) >>
+        char!(',') >>
+        utc_week: opt!(map_res!(take_until!(","), parse_num::<u16>)) >>
+        char!(',') >>
+        leap_seconds: opt!(map_res!(is_not!("D,"), parse_num::<u8>)) >>
+        leap_seconds_default: opt!(char!('D')) >>
+        char!(',') >>
+        clock_bias: opt!(map_res!(take_until!(","), parse_num::<i32>)) >>
+        char!(',') >>
+        clock_drift: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        time_pulse_granularity: opt!(map_res!(is_not!(",*"), parse_num::<u32>)) >>
+        opt!(char!(',')) >>
+        char!('*') >>
+        (PubxData::Time(PubxTimeData {
+            time,
+            date,
+            utc_time_of_week,
+            utc_week,
+            leap_seconds,
+            leap_seconds_default: leap_seconds_default.is_some(),
+            clock_bias,
+            clock_drift,
+            time_pulse_granularity,
+        }))
+    )
+);
+
+named!(pub (crate) parse_pubx<PubxData>,
+    alt!(parse_pubx_position | parse_pubx_satellites | parse_pubx_time)
+);
@@ -17,8 +17,8 @@ fn build_rma<'a>(
     Ok(RmaData {
         status: translate_option!(sentence.0, RmStatus),
         position: sentence.1,
-        time_diff_a: sentence.2,
-        time_diff_b: sentence.3,
+        time_diff_a: loran_td(sentence.2, None),
+        time_diff_b: loran_td(sentence.3, None),
         speed: sentence.4,
         heading: sentence.5,
         magnetic_variation: sentence.6,
@@ -50,3 +50,42 @@ named!(pub (crate) parse_rma<RmaData>,
         build_rma
     )
 );
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{
+        GpsPosition, LatitudeDirection, LongitudeDirection, LoranTd, RmStatus, RmaData,
+        SentenceData,
+    };
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_loran_fixes() {
+        assert_eq!(
+            parse_nmea_sentence(
+                b"$LCRMA,A,5603.370,N,01859.976,E,13028.41,26891.34,5.3,081.2,3.5,E*60\r\n"
+            ),
+            Ok(SentenceData::RMA(RmaData {
+                status: Some(RmStatus::Active),
+                position: GpsPosition {
+                    lat: 56. + 3.370 / 60.,
+                    lat_dir: LatitudeDirection::North,
+                    lon: 18. + 59.976 / 60.,
+                    lon_dir: LongitudeDirection::East,
+                },
+                time_diff_a: Some(LoranTd {
+                    microseconds: 13028.41,
+                    status: None,
+                }),
+                time_diff_b: Some(LoranTd {
+                    microseconds: 26891.34,
+                    status: None,
+                }),
+                speed: Some(5.3),
+                heading: Some(81.2),
+                magnetic_variation: Some(3.5),
+                magnetic_direction: Some(LongitudeDirection::East),
+            }))
+        );
+    }
+}
@@ -0,0 +1,20 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_roo(waypoints: Option<&[u8]>) -> Result<RooData<'_>, NmeaSentenceError<'_>> {
+    Ok(RooData {
+        waypoints: parse_waypoint_list(waypoints.unwrap_or(b""))?,
+    })
+}
+
+named!(pub (crate) parse_roo<RooData>,
+    map_res!(
+        do_parse!(
+            waypoints: opt!(is_not!("*")) >>
+            char!('*') >>
+            (waypoints)
+        ),
+        build_roo
+    )
+);
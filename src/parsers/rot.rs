@@ -0,0 +1,47 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_rot<'a>(sentence: (Option<f32>, Option<char>)) -> Result<RotData, NmeaSentenceError<'a>> {
+    Ok(RotData {
+        rate_of_turn: sentence.0,
+        status: translate_option!(sentence.1, DataValidity),
+    })
+}
+
+named!(pub (crate) parse_rot<RotData>,
+    map_res!(
+        do_parse!(
+            rate_of_turn: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            status: opt!(one_of!("AV")) >>
+            char!('*') >>
+            (rate_of_turn, status)
+        ),
+        build_rot
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{DataValidity, RotData, SentenceData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_rate_of_turn() {
+        assert_eq!(
+            parse_nmea_sentence(b"$HEROT,-11.3,A*35\r\n"),
+            Ok(SentenceData::ROT(RotData {
+                rate_of_turn: Some(-11.3),
+                status: Some(DataValidity::DataValid),
+            }))
+        );
+        assert_eq!(
+            parse_nmea_sentence(b"$HEROT,,V*12\r\n"),
+            Ok(SentenceData::ROT(RotData {
+                rate_of_turn: None,
+                status: Some(DataValidity::DataInvalid),
+            }))
+        );
+    }
+}
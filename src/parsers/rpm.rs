@@ -0,0 +1,74 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_rpm<'a>(
+    sentence: (
+        Option<char>,
+        Option<u8>,
+        Option<f32>,
+        Option<f32>,
+        Option<char>,
+    ),
+) -> Result<RpmData, NmeaSentenceError<'a>> {
+    Ok(RpmData {
+        source: translate_option!(sentence.0, RpmSource),
+        number: sentence.1,
+        rpm: sentence.2,
+        pitch: sentence.3,
+        status: translate_option!(sentence.4, DataValidity),
+    })
+}
+
+named!(pub (crate) parse_rpm<RpmData>,
+    map_res!(
+        do_parse!(
+            source: opt!(one_of!("SE")) >>
+            char!(',') >>
+            number: opt!(map_res!(take_until!(","), parse_num::<u8>)) >>
+            char!(',') >>
+            rpm: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            pitch: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            status: opt!(one_of!("AV")) >>
+            char!('*') >>
+            (source, number, rpm, pitch, status)
+        ),
+        build_rpm
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{DataValidity, RpmData, RpmSource, SentenceData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_engine_revolutions() {
+        assert_eq!(
+            parse_nmea_sentence(b"$IIRPM,E,1,2418.2,10.5,A*5F\r\n"),
+            Ok(SentenceData::RPM(RpmData {
+                source: Some(RpmSource::Engine),
+                number: Some(1),
+                rpm: Some(2418.2),
+                pitch: Some(10.5),
+                status: Some(DataValidity::DataValid),
+            }))
+        );
+    }
+
+    #[test]
+    fn parses_counter_clockwise_shaft_revolutions() {
+        assert_eq!(
+            parse_nmea_sentence(b"$IIRPM,S,2,-750.0,-25.0,V*61\r\n"),
+            Ok(SentenceData::RPM(RpmData {
+                source: Some(RpmSource::Shaft),
+                number: Some(2),
+                rpm: Some(-750.0),
+                pitch: Some(-25.0),
+                status: Some(DataValidity::DataInvalid),
+            }))
+        );
+    }
+}
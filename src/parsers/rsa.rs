@@ -0,0 +1,63 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_rsa<'a>(
+    sentence: (Option<f32>, Option<char>, Option<f32>, Option<char>),
+) -> Result<RsaData, NmeaSentenceError<'a>> {
+    Ok(RsaData {
+        starboard_angle: sentence.0,
+        starboard_status: translate_option!(sentence.1, DataValidity),
+        port_angle: sentence.2,
+        port_status: translate_option!(sentence.3, DataValidity),
+    })
+}
+
+named!(pub (crate) parse_rsa<RsaData>,
+    map_res!(
+        do_parse!(
+            starboard_angle: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            starboard_status: opt!(one_of!("AV")) >>
+            char!(',') >>
+            port_angle: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            port_status: opt!(one_of!("AV")) >>
+            char!('*') >>
+            (starboard_angle, starboard_status, port_angle, port_status)
+        ),
+        build_rsa
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{DataValidity, RsaData, SentenceData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_a_single_rudder() {
+        assert_eq!(
+            parse_nmea_sentence(b"$IIRSA,10.5,A,,V*4D\r\n"),
+            Ok(SentenceData::RSA(RsaData {
+                starboard_angle: Some(10.5),
+                starboard_status: Some(DataValidity::DataValid),
+                port_angle: None,
+                port_status: Some(DataValidity::DataInvalid),
+            }))
+        );
+    }
+
+    #[test]
+    fn parses_two_rudders_turned_to_port() {
+        assert_eq!(
+            parse_nmea_sentence(b"$IIRSA,-5.0,A,-4.5,A*44\r\n"),
+            Ok(SentenceData::RSA(RsaData {
+                starboard_angle: Some(-5.0),
+                starboard_status: Some(DataValidity::DataValid),
+                port_angle: Some(-4.5),
+                port_status: Some(DataValidity::DataValid),
+            }))
+        );
+    }
+}
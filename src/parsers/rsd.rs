@@ -0,0 +1,65 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+named!(
+    parse_radar_origin<RadarOrigin>,
+    do_parse!(
+        range: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        bearing: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        variable_range_marker: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        char!(',') >>
+        bearing_line: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        (RadarOrigin {
+            range,
+            bearing,
+            variable_range_marker,
+            bearing_line,
+        })
+    )
+);
+
+fn build_rsd<'a>(
+    sentence: (
+        [RadarOrigin; 2],
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+        Option<char>,
+        Option<char>,
+    ),
+) -> Result<RsdData, NmeaSentenceError<'a>> {
+    Ok(RsdData {
+        origins: sentence.0,
+        cursor_range: sentence.1,
+        cursor_bearing: sentence.2,
+        range_scale: sentence.3,
+        range_unit: translate_option!(sentence.4, DistanceUnit),
+        display_rotation: translate_option!(sentence.5, DisplayRotation),
+    })
+}
+
+named!(pub (crate) parse_rsd<RsdData>,
+    map_res!(
+        do_parse!(
+            first: parse_radar_origin >>
+            char!(',') >>
+            second: parse_radar_origin >>
+            char!(',') >>
+            cursor_range: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            cursor_bearing: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            range_scale: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            range_unit: opt!(one_of!("NKS")) >>
+            char!(',') >>
+            display_rotation: opt!(one_of!("CHN")) >>
+            char!('*') >>
+            ([first, second], cursor_range, cursor_bearing, range_scale, range_unit, display_rotation)
+        ),
+        build_rsd
+    )
+);
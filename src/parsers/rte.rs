@@ -0,0 +1,43 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_rte<'a>(
+    sentence: (
+        Option<u16>,
+        Option<u16>,
+        Option<char>,
+        Option<&'a [u8]>,
+        Option<Option<&'a [u8]>>,
+    ),
+) -> Result<RteData<'a>, NmeaSentenceError<'a>> {
+    Ok(RteData {
+        number_of_sentences: sentence.0,
+        sentence_num: sentence.1,
+        mode: translate_option!(sentence.2, RteMode),
+        route_id: sentence.3,
+        waypoints: parse_waypoint_list(sentence.4.unwrap_or(None).unwrap_or(b""))?,
+    })
+}
+
+named!(pub (crate) parse_rte<RteData>,
+    map_res!(
+        do_parse!(
+            number_of_sentences: opt!(map_res!(take_until!(","), parse_num::<u16>)) >>
+            char!(',') >>
+            sentence_num: opt!(map_res!(take_until!(","), parse_num::<u16>)) >>
+            char!(',') >>
+            mode: opt!(one_of!("cw")) >>
+            char!(',') >>
+            route_id: opt!(is_not!(",*")) >>
+            waypoints: opt!(complete!(do_parse!(
+                char!(',') >>
+                waypoints: opt!(is_not!("*")) >>
+                (waypoints)
+            ))) >>
+            char!('*') >>
+            (number_of_sentences, sentence_num, mode, route_id, waypoints)
+        ),
+        build_rte
+    )
+);
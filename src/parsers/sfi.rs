@@ -0,0 +1,104 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_sfi<'a>(sentence: (Option<u16>, &'a [u8])) -> Result<SfiData, NmeaSentenceError<'a>> {
+    let mut fields = sentence.1.split(|byte| *byte == b',');
+    let sentence_num = match fields.next() {
+        Some(b"") | None => None,
+        Some(num) => Some(parse_num::<u16>(num)?),
+    };
+
+    let mut frequencies = [None; 6];
+    for (index, frequency) in frequencies.iter_mut().enumerate() {
+        let (value, mode) = match (fields.next(), fields.next()) {
+            (Some(value), Some(mode)) => (value, mode),
+            (None, None) => break,
+            // An empty list of frequencies may still be preceded by a comma
+            (Some(b""), None) if index == 0 => break,
+            _ => return Err(NmeaSentenceError::GeneralParsingError),
+        };
+        if value.is_empty() {
+            continue;
+        }
+
+        let mode = match mode {
+            [] => None,
+            [mode] => Some(FrequencyMode::try_from(*mode as char)?),
+            _ => return Err(NmeaSentenceError::GeneralParsingError),
+        };
+        *frequency = Some(ScanFrequency {
+            frequency: parse_num::<u32>(value)?,
+            mode,
+        });
+    }
+
+    if fields.next().is_some() {
+        return Err(NmeaSentenceError::GeneralParsingError);
+    }
+
+    Ok(SfiData {
+        number_of_sentences: sentence.0,
+        sentence_num,
+        frequencies,
+    })
+}
+
+named!(pub (crate) parse_sfi<SfiData>,
+    map_res!(
+        do_parse!(
+            number_of_sentences: opt!(map_res!(take_until!(","), parse_num::<u16>)) >>
+            char!(',') >>
+            fields: take_until!("*") >>
+            char!('*') >>
+            (number_of_sentences, fields)
+        ),
+        build_sfi
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{FrequencyMode, ScanFrequency, SentenceData, SfiData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_frequency_pairs() {
+        let data = parse_nmea_sentence(b"$CDSFI,2,1,,,021460,e*3C\r\n");
+        let mut frequencies = [None; 6];
+        frequencies[1] = Some(ScanFrequency {
+            frequency: 21460,
+            mode: Some(FrequencyMode::DuplexTelephone),
+        });
+        assert_eq!(
+            data,
+            Ok(SentenceData::SFI(SfiData {
+                number_of_sentences: Some(2),
+                sentence_num: Some(1),
+                frequencies,
+            }))
+        );
+    }
+
+    #[test]
+    fn parses_empty_frequency_list() {
+        let empty = SfiData {
+            number_of_sentences: Some(1),
+            sentence_num: Some(1),
+            frequencies: [None; 6],
+        };
+        assert_eq!(
+            parse_nmea_sentence(b"$CDSFI,1,1,*77"),
+            Ok(SentenceData::SFI(empty))
+        );
+        assert_eq!(
+            parse_nmea_sentence(b"$CDSFI,1,1*5B"),
+            Ok(SentenceData::SFI(empty))
+        );
+    }
+
+    #[test]
+    fn rejects_incomplete_frequency_pairs() {
+        assert!(parse_nmea_sentence(b"$CDSFI,1,1,021450*75").is_err());
+    }
+}
@@ -0,0 +1,92 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_tll<'a>(
+    sentence: (
+        Option<u8>,
+        Option<GpsPosition>,
+        Option<&'a [u8]>,
+        Option<GpsTime>,
+        Option<char>,
+        Option<char>,
+    ),
+) -> Result<TllData<'a>, NmeaSentenceError<'a>> {
+    Ok(TllData {
+        target_number: sentence.0,
+        position: sentence.1,
+        target_name: sentence.2,
+        time: sentence.3,
+        status: translate_option!(sentence.4, TargetStatus),
+        reference_target: sentence.5.is_some(),
+    })
+}
+
+named!(pub (crate) parse_tll<TllData>,
+    map_res!(
+        do_parse!(
+            target_number: opt!(map_res!(take_until!(","), parse_num::<u8>)) >>
+            char!(',') >>
+            position: parse_optional_position >>
+            char!(',') >>
+            target_name: opt!(is_not!(",")) >>
+            char!(',') >>
+            time: opt!(complete!(parse_utc_stamp)) >>
+            char!(',') >>
+            status: opt!(one_of!("LQT")) >>
+            char!(',') >>
+            reference_target: opt!(char!('R')) >>
+            char!('*') >>
+            (target_number, position, target_name, time, status, reference_target)
+        ),
+        build_tll
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{
+        GpsPosition, GpsTime, LatitudeDirection, LongitudeDirection, SentenceData, TargetStatus,
+        TllData,
+    };
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_target_positions() {
+        assert_eq!(
+            parse_nmea_sentence(b"$RATLL,01,5603.370,N,01859.976,E,ALPHA,015200.36,T,*75\r\n"),
+            Ok(SentenceData::TLL(TllData {
+                target_number: Some(1),
+                position: Some(GpsPosition {
+                    lat: 56. + 3.370 / 60.,
+                    lat_dir: LatitudeDirection::North,
+                    lon: 18. + 59.976 / 60.,
+                    lon_dir: LongitudeDirection::East,
+                }),
+                target_name: Some(b"ALPHA"),
+                time: Some(GpsTime {
+                    hour: 1,
+                    minute: 52,
+                    second: 0.36,
+                }),
+                status: Some(TargetStatus::Tracking),
+                reference_target: false,
+            }))
+        );
+    }
+
+    #[test]
+    fn parses_lost_targets_without_position() {
+        assert_eq!(
+            parse_nmea_sentence(b"$RATLL,02,,,,,,,L,*25\r\n"),
+            Ok(SentenceData::TLL(TllData {
+                target_number: Some(2),
+                position: None,
+                target_name: None,
+                time: None,
+                status: Some(TargetStatus::Lost),
+                reference_target: false,
+            }))
+        );
+    }
+}
@@ -0,0 +1,155 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_ttm<'a>(
+    sentence: (
+        (
+            Option<u8>,
+            Option<f32>,
+            Option<f32>,
+            Option<char>,
+            Option<f32>,
+            Option<f32>,
+            Option<char>,
+        ),
+        (
+            Option<f32>,
+            Option<f32>,
+            Option<char>,
+            Option<&'a [u8]>,
+            Option<char>,
+            Option<char>,
+        ),
+        Option<(Option<GpsTime>, Option<char>)>,
+    ),
+) -> Result<TtmData<'a>, NmeaSentenceError<'a>> {
+    let (motion, approach, extension) = sentence;
+    let (time, acquisition) = extension.unwrap_or((None, None));
+    Ok(TtmData {
+        target_number: motion.0,
+        distance: motion.1,
+        bearing: motion.2,
+        bearing_reference: translate_option!(motion.3, TargetReference),
+        speed: motion.4,
+        course: motion.5,
+        course_reference: translate_option!(motion.6, TargetReference),
+        cpa_distance: approach.0,
+        tcpa: approach.1,
+        unit: translate_option!(approach.2, DistanceUnit),
+        target_name: approach.3,
+        status: translate_option!(approach.4, TargetStatus),
+        reference_target: approach.5.is_some(),
+        time,
+        acquisition: translate_option!(acquisition, TargetAcquisition),
+    })
+}
+
+named!(pub (crate) parse_ttm<TtmData>,
+    map_res!(
+        do_parse!(
+            target_number: opt!(map_res!(take_until!(","), parse_num::<u8>)) >>
+            char!(',') >>
+            distance: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            bearing: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            bearing_reference: opt!(one_of!("TR")) >>
+            char!(',') >>
+            speed: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            course: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            course_reference: opt!(one_of!("TR")) >>
+            char!(',') >>
+            cpa_distance: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            tcpa: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            unit: opt!(one_of!("NKS")) >>
+            char!(',') >>
+            target_name: opt!(is_not!(",")) >>
+            char!(',') >>
+            status: opt!(one_of!("LQT")) >>
+            char!(',') >>
+            reference_target: opt!(char!('R')) >>
+            extension: opt!(complete!(do_parse!(
+                char!(',') >>
+                time: opt!(complete!(parse_utc_stamp)) >>
+                char!(',') >>
+                acquisition: opt!(one_of!("AMR")) >>
+                (time, acquisition)
+            ))) >>
+            char!('*') >>
+            (
+                (target_number, distance, bearing, bearing_reference, speed, course, course_reference),
+                (cpa_distance, tcpa, unit, target_name, status, reference_target),
+                extension
+            )
+        ),
+        build_ttm
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{
+        DistanceUnit, GpsTime, SentenceData, TargetAcquisition, TargetReference, TargetStatus,
+        TtmData,
+    };
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_tracked_targets() {
+        assert_eq!(
+            parse_nmea_sentence(b"$RATTM,11,11.4,13.6,T,7.0,20.0,T,0.2,-3.5,N,TARGET11,T,*65\r\n"),
+            Ok(SentenceData::TTM(TtmData {
+                target_number: Some(11),
+                distance: Some(11.4),
+                bearing: Some(13.6),
+                bearing_reference: Some(TargetReference::True),
+                speed: Some(7.0),
+                course: Some(20.0),
+                course_reference: Some(TargetReference::True),
+                cpa_distance: Some(0.2),
+                tcpa: Some(-3.5),
+                unit: Some(DistanceUnit::NauticalMiles),
+                target_name: Some(b"TARGET11"),
+                status: Some(TargetStatus::Tracking),
+                reference_target: false,
+                time: None,
+                acquisition: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn parses_the_time_and_acquisition_of_nmea_3() {
+        assert_eq!(
+            parse_nmea_sentence(
+                b"$RATTM,02,1.43,170.5,T,0.16,264.4,T,1.42,36.9,N,,T,R,100259.00,A*55\r\n"
+            ),
+            Ok(SentenceData::TTM(TtmData {
+                target_number: Some(2),
+                distance: Some(1.43),
+                bearing: Some(170.5),
+                bearing_reference: Some(TargetReference::True),
+                speed: Some(0.16),
+                course: Some(264.4),
+                course_reference: Some(TargetReference::True),
+                cpa_distance: Some(1.42),
+                tcpa: Some(36.9),
+                unit: Some(DistanceUnit::NauticalMiles),
+                target_name: None,
+                status: Some(TargetStatus::Tracking),
+                reference_target: true,
+                time: Some(GpsTime {
+                    hour: 10,
+                    minute: 2,
+                    second: 59.0,
+                }),
+                acquisition: Some(TargetAcquisition::Automatic),
+            }))
+        );
+    }
+}
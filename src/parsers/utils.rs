@@ -2,7 +2,7 @@ use crate::errors::NmeaSentenceError;
 use crate::parse::*;
 pub(crate) use nom::{map_res, named, one_of, opt, tag, take, take_until};
 
-pub(crate) fn parse_num<I: core::str::FromStr>(data: &[u8]) -> Result<I, NmeaSentenceError> {
+pub(crate) fn parse_num<I: core::str::FromStr>(data: &[u8]) -> Result<I, NmeaSentenceError<'_>> {
     str::parse::<I>(unsafe { core::str::from_utf8_unchecked(data) })
         .map_err(|_| NmeaSentenceError::GeneralParsingError)
 }
@@ -79,12 +79,60 @@ named!(pub (crate) parse_gps_position<GpsPosition>,
 
 pub(crate) fn invalid_height_check<'a>(
     height: Option<&'a [u8]>,
-) -> Result<Option<f32>, NmeaSentenceError> {
+) -> Result<Option<f32>, NmeaSentenceError<'a>> {
     Ok(match height {
         Some(val) => match val {
-            b"-" => None,
+            b"-" | b"" => None,
             val => Some(parse_num::<f32>(val)?),
         },
         None => None,
     })
 }
+
+/// Parses a hex encoded field such as the almanac words of ALM
+pub(crate) fn parse_hex_num(data: &[u8]) -> Result<u32, NmeaSentenceError<'_>> {
+    let text = core::str::from_utf8(data).map_err(|_| NmeaSentenceError::GeneralParsingError)?;
+    u32::from_str_radix(text, 16).map_err(|_| NmeaSentenceError::GeneralParsingError)
+}
+
+/// Interprets the lowest bits of a value as a two's complement number
+pub(crate) fn sign_extend(value: u32, bits: u32) -> i32 {
+    let shift = 32 - bits;
+    ((value << shift) as i32) >> shift
+}
+
+/// Combines a Loran-C time difference with the status of its signal,
+/// a status without a time difference is dropped
+pub(crate) fn loran_td(microseconds: Option<f32>, status: Option<LoranStatus>) -> Option<LoranTd> {
+    microseconds.map(|microseconds| LoranTd {
+        microseconds,
+        status,
+    })
+}
+
+/// Splits the comma separated waypoint list of RTE and ROO, empty identifiers are kept as None
+pub(crate) fn parse_waypoint_list(list: &[u8]) -> Result<RteWaypoints<'_>, NmeaSentenceError<'_>> {
+    #[cfg(feature = "alloc")]
+    let waypoints = Ok(match list {
+        [] => RteWaypoints::new(),
+        list => list
+            .split(|byte| *byte == b',')
+            .map(|waypoint| match waypoint {
+                [] => None,
+                waypoint => Some(waypoint),
+            })
+            .collect(),
+    });
+    #[cfg(not(feature = "alloc"))]
+    let waypoints = RteWaypoints::new(list);
+
+    waypoints
+}
+
+// Parses a position whose four fields may all be left empty
+named!(pub (crate) parse_optional_position<Option<GpsPosition>>,
+    alt!(
+        map!(complete!(parse_gps_position), Some) |
+        value!(None, tag!(",,,"))
+    )
+);
@@ -11,7 +11,7 @@ fn build_vbw<'a>(
         Option<&'a [u8]>,
         Option<char>,
     ),
-) -> Result<VbwData, NmeaSentenceError> {
+) -> Result<VbwData, NmeaSentenceError<'a>> {
     Ok(VbwData {
         lon_water_speed: invalid_height_check(sentence.0)?,
         transverse_water_speed: invalid_height_check(sentence.1)?,
@@ -0,0 +1,51 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_vdm<'a>(
+    sentence: (
+        Option<u16>,
+        Option<u16>,
+        Option<u8>,
+        Option<char>,
+        Option<&'a [u8]>,
+        Option<u8>,
+    ),
+) -> Result<VdmData<'a>, NmeaSentenceError<'a>> {
+    // Some receivers number the channels instead of naming them
+    let channel = match sentence.3 {
+        Some('1') => Some('A'),
+        Some('2') => Some('B'),
+        channel => channel,
+    };
+
+    Ok(VdmData {
+        fragment_count: sentence.0,
+        fragment_number: sentence.1,
+        message_id: sentence.2,
+        channel: translate_option!(channel, AisChannel),
+        payload: sentence.4.unwrap_or(b""),
+        fill_bits: sentence.5,
+    })
+}
+
+named!(pub (crate) parse_vdm<VdmData>,
+    map_res!(
+        do_parse!(
+            fragment_count: opt!(map_res!(take_until!(","), parse_num::<u16>)) >>
+            char!(',') >>
+            fragment_number: opt!(map_res!(take_until!(","), parse_num::<u16>)) >>
+            char!(',') >>
+            message_id: opt!(map_res!(take_until!(","), parse_num::<u8>)) >>
+            char!(',') >>
+            channel: opt!(one_of!("AB12")) >>
+            char!(',') >>
+            payload: opt!(is_not!(",")) >>
+            char!(',') >>
+            fill_bits: opt!(map_res!(take_until!("*"), parse_num::<u8>)) >>
+            char!('*') >>
+            (fragment_count, fragment_number, message_id, channel, payload, fill_bits)
+        ),
+        build_vdm
+    )
+);
@@ -0,0 +1,50 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+named!(pub (crate) parse_vdr<VdrData>,
+    map_res!(
+        do_parse!(
+            set_true: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",T,") >>
+            set_magnetic: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",M,") >>
+            drift_knots: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",N*") >>
+            (set_true, set_magnetic, drift_knots)
+        ),
+        | sentence: (Option<f32>, Option<f32>, Option<f32>)| -> Result<VdrData, NmeaSentenceError> {
+            Ok(VdrData{
+                set_true: sentence.0,
+                set_magnetic: sentence.1,
+                drift_knots: sentence.2,
+            })
+        }
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{SentenceData, VdrData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_set_and_drift() {
+        assert_eq!(
+            parse_nmea_sentence(b"$IIVDR,10.1,T,12.3,M,1.2,N*3A\r\n"),
+            Ok(SentenceData::VDR(VdrData {
+                set_true: Some(10.1),
+                set_magnetic: Some(12.3),
+                drift_knots: Some(1.2),
+            }))
+        );
+        assert_eq!(
+            parse_nmea_sentence(b"$IIVDR,,T,,M,,N*17\r\n"),
+            Ok(SentenceData::VDR(VdrData {
+                set_true: None,
+                set_magnetic: None,
+                drift_knots: None,
+            }))
+        );
+    }
+}
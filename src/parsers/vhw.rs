@@ -0,0 +1,55 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+named!(pub (crate) parse_vhw<VhwData>,
+    map_res!(
+        do_parse!(
+            heading_true: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",T,") >>
+            heading_magnetic: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",M,") >>
+            speed_knots: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",N,") >>
+            speed_kmh: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",K*") >>
+            (heading_true, heading_magnetic, speed_knots, speed_kmh)
+        ),
+        | sentence: (Option<f32>, Option<f32>, Option<f32>, Option<f32>)| -> Result<VhwData, NmeaSentenceError> {
+            Ok(VhwData{
+                heading_true: sentence.0,
+                heading_magnetic: sentence.1,
+                speed_knots: sentence.2,
+                speed_kmh: sentence.3,
+            })
+        }
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{SentenceData, VhwData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_heading_and_water_speed() {
+        assert_eq!(
+            parse_nmea_sentence(b"$IIVHW,245.1,T,245.1,M,000.01,N,000.01,K*55\r\n"),
+            Ok(SentenceData::VHW(VhwData {
+                heading_true: Some(245.1),
+                heading_magnetic: Some(245.1),
+                speed_knots: Some(0.01),
+                speed_kmh: Some(0.01),
+            }))
+        );
+        assert_eq!(
+            parse_nmea_sentence(b"$IIVHW,,T,,M,3.5,N,6.5,K*50\r\n"),
+            Ok(SentenceData::VHW(VhwData {
+                heading_true: None,
+                heading_magnetic: None,
+                speed_knots: Some(3.5),
+                speed_kmh: Some(6.5),
+            }))
+        );
+    }
+}
@@ -0,0 +1,69 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_vlw<'a>(
+    sentence: (Option<f32>, Option<f32>, Option<(Option<f32>, Option<f32>)>),
+) -> Result<VlwData, NmeaSentenceError<'a>> {
+    let (total_ground_distance, trip_ground_distance) = sentence.2.unwrap_or((None, None));
+    Ok(VlwData {
+        total_water_distance: sentence.0,
+        trip_water_distance: sentence.1,
+        total_ground_distance,
+        trip_ground_distance,
+    })
+}
+
+named!(pub (crate) parse_vlw<VlwData>,
+    map_res!(
+        do_parse!(
+            total_water_distance: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",N,") >>
+            trip_water_distance: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",N") >>
+            ground_distance: opt!(complete!(do_parse!(
+                char!(',') >>
+                total_ground_distance: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+                tag!(",N,") >>
+                trip_ground_distance: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+                tag!(",N") >>
+                (total_ground_distance, trip_ground_distance)
+            ))) >>
+            char!('*') >>
+            (total_water_distance, trip_water_distance, ground_distance)
+        ),
+        build_vlw
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{SentenceData, VlwData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_water_distances() {
+        assert_eq!(
+            parse_nmea_sentence(b"$IIVLW,7803.2,N,0.00,N*43\r\n"),
+            Ok(SentenceData::VLW(VlwData {
+                total_water_distance: Some(7803.2),
+                trip_water_distance: Some(0.0),
+                total_ground_distance: None,
+                trip_ground_distance: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn parses_the_ground_distances_of_nmea_3() {
+        assert_eq!(
+            parse_nmea_sentence(b"$IIVLW,2.8,N,2.8,N,1034.2,N,12.5,N*4F\r\n"),
+            Ok(SentenceData::VLW(VlwData {
+                total_water_distance: Some(2.8),
+                trip_water_distance: Some(2.8),
+                total_ground_distance: Some(1034.2),
+                trip_ground_distance: Some(12.5),
+            }))
+        );
+    }
+}
@@ -0,0 +1,15 @@
+use super::utils::*;
+use crate::parse::*;
+
+named!(pub (crate) parse_vpw<VpwData>,
+    do_parse!(
+        speed_knots: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        tag!(",N,") >>
+        speed_mps: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+        tag!(",M*") >>
+        (VpwData {
+            speed_knots,
+            speed_mps,
+        })
+    )
+);
@@ -0,0 +1,70 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_vwr<'a>(
+    sentence: (
+        Option<f32>,
+        Option<char>,
+        Option<f32>,
+        Option<f32>,
+        Option<f32>,
+    ),
+) -> Result<VwrData, NmeaSentenceError<'a>> {
+    Ok(VwrData {
+        angle: sentence.0,
+        side: translate_option!(sentence.1, WindSide),
+        speed_knots: sentence.2,
+        speed_mps: sentence.3,
+        speed_kmh: sentence.4,
+    })
+}
+
+named!(pub (crate) parse_vwr<VwrData>,
+    map_res!(
+        do_parse!(
+            angle: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            side: opt!(one_of!("LR")) >>
+            char!(',') >>
+            speed_knots: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",N,") >>
+            speed_mps: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",M,") >>
+            speed_kmh: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",K*") >>
+            (angle, side, speed_knots, speed_mps, speed_kmh)
+        ),
+        build_vwr
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{SentenceData, VwrData, WindSide};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_relative_wind() {
+        assert_eq!(
+            parse_nmea_sentence(b"$IIVWR,148.,L,02.4,N,01.2,M,04.4,K*5F\r\n"),
+            Ok(SentenceData::VWR(VwrData {
+                angle: Some(148.0),
+                side: Some(WindSide::Left),
+                speed_knots: Some(2.4),
+                speed_mps: Some(1.2),
+                speed_kmh: Some(4.4),
+            }))
+        );
+        assert_eq!(
+            parse_nmea_sentence(b"$IIVWR,,,,N,,M,,K*1B\r\n"),
+            Ok(SentenceData::VWR(VwrData {
+                angle: None,
+                side: None,
+                speed_knots: None,
+                speed_mps: None,
+                speed_kmh: None,
+            }))
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_wcv<'a>(
+    sentence: (Option<f32>, Option<&'a [u8]>, Option<Option<char>>),
+) -> Result<WcvData<'a>, NmeaSentenceError<'a>> {
+    Ok(WcvData {
+        velocity_knots: sentence.0,
+        waypoint: sentence.1,
+        mode: translate_option!(sentence.2.unwrap_or(None), GnsMode),
+    })
+}
+
+named!(pub (crate) parse_wcv<WcvData>,
+    map_res!(
+        do_parse!(
+            velocity_knots: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",N,") >>
+            waypoint: opt!(is_not!(",*")) >>
+            mode: opt!(complete!(do_parse!(
+                char!(',') >>
+                mode: opt!(one_of!("NADPRFEMS")) >>
+                (mode)
+            ))) >>
+            char!('*') >>
+            (velocity_knots, waypoint, mode)
+        ),
+        build_wcv
+    )
+);
@@ -0,0 +1,31 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_wnc<'a>(
+    sentence: (Option<f32>, Option<f32>, Option<&'a [u8]>, Option<&'a [u8]>),
+) -> Result<WncData<'a>, NmeaSentenceError<'a>> {
+    Ok(WncData {
+        nautical_miles: sentence.0,
+        kilometers: sentence.1,
+        to_waypoint: sentence.2,
+        from_waypoint: sentence.3,
+    })
+}
+
+named!(pub (crate) parse_wnc<WncData>,
+    map_res!(
+        do_parse!(
+            nautical_miles: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",N,") >>
+            kilometers: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            tag!(",K,") >>
+            to_waypoint: opt!(is_not!(",")) >>
+            char!(',') >>
+            from_waypoint: opt!(is_not!("*")) >>
+            char!('*') >>
+            (nautical_miles, kilometers, to_waypoint, from_waypoint)
+        ),
+        build_wnc
+    )
+);
@@ -0,0 +1,139 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+/// Parses the type, value, unit and name fields of a single measurement
+pub(crate) fn parse_measurement<'a>(
+    fields: [&'a [u8]; 4],
+) -> Result<XdrMeasurement<'a>, NmeaSentenceError<'a>> {
+    let transducer = match fields[0] {
+        [] => None,
+        [transducer] => Some(XdrTransducer::from_char(*transducer as char)),
+        _ => return Err(NmeaSentenceError::GeneralParsingError),
+    };
+    let value = match fields[1] {
+        [] => None,
+        value => Some(parse_num::<f32>(value)?),
+    };
+    let unit = match fields[2] {
+        [] => None,
+        [unit] => Some(XdrUnit::from_char(transducer, *unit as char)),
+        _ => return Err(NmeaSentenceError::GeneralParsingError),
+    };
+    let name = match fields[3] {
+        [] => None,
+        name => Some(name),
+    };
+
+    Ok(XdrMeasurement {
+        transducer,
+        value,
+        unit,
+        name,
+    })
+}
+
+fn build_xdr(measurements: &[u8]) -> Result<XdrData<'_>, NmeaSentenceError<'_>> {
+    if !measurements.is_empty() {
+        let mut fields = measurements.split(|byte| *byte == b',');
+        while let Some(transducer) = fields.next() {
+            match (fields.next(), fields.next(), fields.next()) {
+                (Some(value), Some(unit), Some(name)) => {
+                    parse_measurement([transducer, value, unit, name])?;
+                }
+                _ => return Err(NmeaSentenceError::GeneralParsingError),
+            }
+        }
+    }
+
+    Ok(XdrData { measurements })
+}
+
+named!(pub (crate) parse_xdr<XdrData>,
+    map_res!(
+        do_parse!(
+            measurements: take_until!("*") >>
+            char!('*') >>
+            (measurements)
+        ),
+        build_xdr
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{SentenceData, XdrData, XdrMeasurement, XdrTransducer, XdrUnit};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_typed_measurements() {
+        let xdr = match parse_nmea_sentence(
+            b"$IIXDR,C,19.52,C,TempAir,P,1.02481,B,Barometer,H,61.4,P,Humidity*42\r\n",
+        ) {
+            Ok(SentenceData::XDR(xdr)) => xdr,
+            other => panic!("unexpected result {:?}", other),
+        };
+
+        let mut measurements = xdr.measurements();
+        assert_eq!(
+            measurements.next(),
+            Some(XdrMeasurement {
+                transducer: Some(XdrTransducer::Temperature),
+                value: Some(19.52),
+                unit: Some(XdrUnit::Celsius),
+                name: Some(b"TempAir"),
+            })
+        );
+        assert_eq!(
+            measurements.next(),
+            Some(XdrMeasurement {
+                transducer: Some(XdrTransducer::Pressure),
+                value: Some(1.02481),
+                unit: Some(XdrUnit::Bars),
+                name: Some(b"Barometer"),
+            })
+        );
+        // P is percent for humidity, not pascal
+        assert_eq!(
+            measurements.next(),
+            Some(XdrMeasurement {
+                transducer: Some(XdrTransducer::Humidity),
+                value: Some(61.4),
+                unit: Some(XdrUnit::Percent),
+                name: Some(b"Humidity"),
+            })
+        );
+        assert_eq!(measurements.next(), None);
+    }
+
+    #[test]
+    fn parses_sentences_without_measurements() {
+        match parse_nmea_sentence(b"$IIXDR,*62\r\n") {
+            Ok(SentenceData::XDR(xdr)) => assert_eq!(xdr.measurements().next(), None),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_measurements() {
+        assert!(parse_nmea_sentence(b"$IIXDR,C,19.52,C*43\r\n").is_err());
+        assert!(parse_nmea_sentence(b"$IIXDR,C,abc,C,TempAir*58\r\n").is_err());
+    }
+
+    #[test]
+    fn stops_iterating_at_a_malformed_measurement() {
+        // Parsing verifies every measurement, so this only happens if the data
+        // is constructed without doing so
+        let xdr = XdrData {
+            measurements: b"C,19.52,C,TempAir,C,abc,C,TempWater,C,12.5,C,TempSea",
+        };
+
+        let mut measurements = xdr.measurements();
+        assert_eq!(
+            measurements.next().map(|measurement| measurement.name),
+            Some(Some(&b"TempAir"[..]))
+        );
+        assert_eq!(measurements.next(), None);
+        assert_eq!(xdr.measurements().count(), 1);
+    }
+}
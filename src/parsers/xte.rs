@@ -0,0 +1,94 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_xte<'a>(
+    sentence: (
+        Option<char>,
+        Option<char>,
+        Option<f32>,
+        Option<char>,
+        Option<char>,
+        Option<Option<char>>,
+    ),
+) -> Result<XteData, NmeaSentenceError<'a>> {
+    Ok(XteData {
+        status: translate_option!(sentence.0, DataValidity),
+        cycle_lock_status: translate_option!(sentence.1, DataValidity),
+        cross_track_error: sentence.2,
+        steer_direction: translate_option!(sentence.3, SteerDirection),
+        cross_track_unit: translate_option!(sentence.4, DistanceUnit),
+        mode: translate_option!(sentence.5.unwrap_or(None), GnsMode),
+    })
+}
+
+named!(pub (crate) parse_xte<XteData>,
+    map_res!(
+        do_parse!(
+            status: opt!(one_of!("AV")) >>
+            char!(',') >>
+            cycle_lock_status: opt!(one_of!("AV")) >>
+            char!(',') >>
+            cross_track_error: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            steer_direction: opt!(one_of!("LR")) >>
+            char!(',') >>
+            cross_track_unit: opt!(one_of!("NK")) >>
+            mode: opt!(complete!(do_parse!(
+                char!(',') >>
+                mode: opt!(one_of!("NADPRFEMS")) >>
+                (mode)
+            ))) >>
+            char!('*') >>
+            (status, cycle_lock_status, cross_track_error, steer_direction, cross_track_unit, mode)
+        ),
+        build_xte
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{
+        DataValidity, DistanceUnit, GnsMode, SentenceData, SteerDirection, XteData,
+    };
+    use crate::parse_nmea_sentence;
+
+    const XTE: XteData = XteData {
+        status: Some(DataValidity::DataValid),
+        cycle_lock_status: Some(DataValidity::DataValid),
+        cross_track_error: Some(0.67),
+        steer_direction: Some(SteerDirection::Left),
+        cross_track_unit: Some(DistanceUnit::NauticalMiles),
+        mode: None,
+    };
+
+    #[test]
+    fn parses_cross_track_error() {
+        assert_eq!(
+            parse_nmea_sentence(b"$GPXTE,A,A,0.67,L,N*6F\r\n"),
+            Ok(SentenceData::XTE(XTE))
+        );
+        assert_eq!(
+            parse_nmea_sentence(b"$GPXTE,A,A,0.67,L,N,D*07\r\n"),
+            Ok(SentenceData::XTE(XteData {
+                mode: Some(GnsMode::DifferentialFix),
+                ..XTE
+            }))
+        );
+    }
+
+    #[test]
+    fn parses_an_invalid_cross_track_error() {
+        assert_eq!(
+            parse_nmea_sentence(b"$GPXTE,V,V,,,N,N*5E\r\n"),
+            Ok(SentenceData::XTE(XteData {
+                status: Some(DataValidity::DataInvalid),
+                cycle_lock_status: Some(DataValidity::DataInvalid),
+                cross_track_error: None,
+                steer_direction: None,
+                cross_track_unit: Some(DistanceUnit::NauticalMiles),
+                mode: Some(GnsMode::NoFix),
+            }))
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_xtr<'a>(
+    sentence: (Option<f32>, Option<char>, Option<char>),
+) -> Result<XtrData, NmeaSentenceError<'a>> {
+    Ok(XtrData {
+        cross_track_error: sentence.0,
+        steer_direction: translate_option!(sentence.1, SteerDirection),
+        cross_track_unit: translate_option!(sentence.2, DistanceUnit),
+    })
+}
+
+named!(pub (crate) parse_xtr<XtrData>,
+    map_res!(
+        do_parse!(
+            cross_track_error: opt!(map_res!(take_until!(","), parse_num::<f32>)) >>
+            char!(',') >>
+            steer_direction: opt!(one_of!("LR")) >>
+            char!(',') >>
+            cross_track_unit: opt!(one_of!("NK")) >>
+            char!('*') >>
+            (cross_track_error, steer_direction, cross_track_unit)
+        ),
+        build_xtr
+    )
+);
@@ -0,0 +1,138 @@
+use super::utils::*;
+use crate::errors::NmeaSentenceError;
+use crate::parse::*;
+
+fn build_zda<'a>(
+    sentence: (
+        Option<GpsTime>,
+        Option<u8>,
+        Option<u8>,
+        Option<&'a [u8]>,
+        Option<&'a [u8]>,
+        Option<i8>,
+    ),
+) -> Result<ZdaData, NmeaSentenceError<'a>> {
+    // The year is always sent with all four digits, anything else is not a ZDA year
+    let year = match sentence.3 {
+        Some(year) if year.len() == 4 && year.iter().all(u8::is_ascii_digit) => {
+            Some(parse_num::<u64>(year)?)
+        }
+        Some(_) => return Err(NmeaSentenceError::GeneralParsingError),
+        None => None,
+    };
+
+    let date = match (sentence.1, sentence.2, year) {
+        (Some(day), Some(month), Some(year)) => Some(GpsDate { day, month, year }),
+        _ => None,
+    };
+
+    let local_zone = match (sentence.4, sentence.5) {
+        (Some(hours), Some(minutes)) => {
+            let hours = parse_num::<i8>(hours)?;
+            // The sign of the hours applies to the minutes as well, this includes -00
+            let negative = hours < 0 || sentence.4 == Some(b"-00");
+            Some(LocalZone {
+                hours,
+                minutes: if negative { -minutes } else { minutes },
+            })
+        }
+        _ => None,
+    };
+
+    Ok(ZdaData {
+        time: sentence.0,
+        date,
+        local_zone,
+    })
+}
+
+named!(pub (crate) parse_zda<ZdaData>,
+    map_res!(
+        do_parse!(
+            time: opt!(complete!(parse_utc_stamp)) >>
+            char!(',') >>
+            day: opt!(map_res!(take_until!(","), parse_num::<u8>)) >>
+            char!(',') >>
+            month: opt!(map_res!(take_until!(","), parse_num::<u8>)) >>
+            char!(',') >>
+            year: opt!(is_not!(",")) >>
+            char!(',') >>
+            zone_hours: opt!(is_not!(",")) >>
+            char!(',') >>
+            zone_minutes: opt!(map_res!(take_until!("*"), parse_num::<i8>)) >>
+            char!('*') >>
+            (time, day, month, year, zone_hours, zone_minutes)
+        ),
+        build_zda
+    )
+);
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{GpsDate, GpsTime, LocalZone, SentenceData, ZdaData};
+    use crate::parse_nmea_sentence;
+
+    #[test]
+    fn parses_date_and_time() {
+        assert_eq!(
+            parse_nmea_sentence(b"$GPZDA,201530.00,04,07,2002,00,00*60\r\n"),
+            Ok(SentenceData::ZDA(ZdaData {
+                time: Some(GpsTime {
+                    hour: 20,
+                    minute: 15,
+                    second: 30.0,
+                }),
+                date: Some(GpsDate {
+                    day: 4,
+                    month: 7,
+                    year: 2002,
+                }),
+                local_zone: Some(LocalZone {
+                    hours: 0,
+                    minutes: 0,
+                }),
+            }))
+        );
+        assert_eq!(
+            parse_nmea_sentence(b"$GPZDA,201530.00,,,,,*63\r\n"),
+            Ok(SentenceData::ZDA(ZdaData {
+                time: Some(GpsTime {
+                    hour: 20,
+                    minute: 15,
+                    second: 30.0,
+                }),
+                date: None,
+                local_zone: None,
+            }))
+        );
+    }
+
+    #[test]
+    fn applies_the_sign_of_the_local_zone_hours_to_the_minutes() {
+        let local_zone = |sentence: &[u8]| match parse_nmea_sentence(sentence) {
+            Ok(SentenceData::ZDA(data)) => data.local_zone,
+            other => panic!("unexpected result {:?}", other),
+        };
+
+        assert_eq!(
+            local_zone(b"$GPZDA,050306.00,29,10,2003,-00,30*43\r\n"),
+            Some(LocalZone {
+                hours: 0,
+                minutes: -30,
+            })
+        );
+        assert_eq!(
+            local_zone(b"$GPZDA,160012.71,11,03,2004,-1,00*7D\r\n"),
+            Some(LocalZone {
+                hours: -1,
+                minutes: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_years_without_four_digits() {
+        assert!(parse_nmea_sentence(b"$GPZDA,201530.00,04,07,02,00,00*62\r\n").is_err());
+        assert!(parse_nmea_sentence(b"$GPZDA,201530.00,04,07,20021,00,00*51\r\n").is_err());
+    }
+}
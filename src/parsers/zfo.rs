@@ -0,0 +1,18 @@
+use super::utils::*;
+use crate::parse::*;
+
+named!(pub (crate) parse_zfo<ZfoData>,
+    do_parse!(
+        time: opt!(complete!(parse_utc_stamp)) >>
+        char!(',') >>
+        elapsed_time: opt!(complete!(parse_utc_stamp)) >>
+        char!(',') >>
+        origin_waypoint: opt!(is_not!("*")) >>
+        char!('*') >>
+        (ZfoData {
+            time,
+            elapsed_time,
+            origin_waypoint,
+        })
+    )
+);
@@ -0,0 +1,18 @@
+use super::utils::*;
+use crate::parse::*;
+
+named!(pub (crate) parse_ztg<ZtgData>,
+    do_parse!(
+        time: opt!(complete!(parse_utc_stamp)) >>
+        char!(',') >>
+        time_to_go: opt!(complete!(parse_utc_stamp)) >>
+        char!(',') >>
+        destination_waypoint: opt!(is_not!("*")) >>
+        char!('*') >>
+        (ZtgData {
+            time,
+            time_to_go,
+            destination_waypoint,
+        })
+    )
+);
@@ -0,0 +1,296 @@
+use crate::encoders::{ChecksumWriter, SliceWriter};
+use crate::errors::NmeaEncodeError;
+use core::fmt::Write;
+
+/// The baud rates MediaTek receivers can be switched to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PmtkBaudRate {
+    /// The baud rate the receiver was configured with in its firmware
+    Default,
+    B4800,
+    B9600,
+    B14400,
+    B19200,
+    B38400,
+    B57600,
+    B115200,
+}
+
+impl PmtkBaudRate {
+    fn value(self) -> u32 {
+        match self {
+            PmtkBaudRate::Default => 0,
+            PmtkBaudRate::B4800 => 4800,
+            PmtkBaudRate::B9600 => 9600,
+            PmtkBaudRate::B14400 => 14400,
+            PmtkBaudRate::B19200 => 19200,
+            PmtkBaudRate::B38400 => 38400,
+            PmtkBaudRate::B57600 => 57600,
+            PmtkBaudRate::B115200 => 115200,
+        }
+    }
+}
+
+/// The source of differential corrections a MediaTek receiver uses
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PmtkDgpsMode {
+    NoDgps,
+    Rtcm,
+    Sbas,
+}
+
+/// The rate each sentence is output at, 0 disables the sentence and
+/// 1 to 5 output it once every that many position fixes
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct PmtkOutputMask {
+    pub gll: u8,
+    pub rmc: u8,
+    pub vtg: u8,
+    pub gga: u8,
+    pub gsa: u8,
+    pub gsv: u8,
+    pub zda: u8,
+    /// The MediaTek proprietary channel status sentence PMTKCHN
+    pub mchn: u8,
+}
+
+impl PmtkOutputMask {
+    /// The rates in the order PMTK314 expects them
+    fn rates(&self) -> [u8; 19] {
+        let mut rates = [0; 19];
+        rates[0] = self.gll;
+        rates[1] = self.rmc;
+        rates[2] = self.vtg;
+        rates[3] = self.gga;
+        rates[4] = self.gsa;
+        rates[5] = self.gsv;
+        rates[17] = self.zda;
+        rates[18] = self.mchn;
+        rates
+    }
+}
+
+/// The commands commonly used to configure MediaTek receivers
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PmtkCommand {
+    /// Restarts the receiver using all data it has available
+    HotStart,
+    /// Restarts the receiver without using the ephemeris
+    WarmStart,
+    /// Restarts the receiver without using time, position, almanac and ephemeris
+    ColdStart,
+    /// A cold start that additionally resets the configuration to the factory defaults
+    FullColdStart,
+    /// Puts the receiver into standby until it receives the next byte
+    Standby,
+    /// Sets the interval between position fixes in milliseconds, 100 to 10000
+    SetUpdateRate(u16),
+    SetBaudRate(PmtkBaudRate),
+    SetDgpsMode(PmtkDgpsMode),
+    /// Enables or disables the search for SBAS satellites
+    SetSbas(bool),
+    SetNmeaOutput(PmtkOutputMask),
+    /// Restores the sentence output rates of the firmware
+    ResetNmeaOutput,
+    /// Requests the firmware release, the receiver replies with PMTK705
+    QueryFirmware,
+}
+
+impl PmtkCommand {
+    /// The number of the command, this is what a PMTK001 acknowledgement refers to
+    pub fn id(&self) -> u16 {
+        match self {
+            PmtkCommand::HotStart => 101,
+            PmtkCommand::WarmStart => 102,
+            PmtkCommand::ColdStart => 103,
+            PmtkCommand::FullColdStart => 104,
+            PmtkCommand::Standby => 161,
+            PmtkCommand::SetUpdateRate(_) => 220,
+            PmtkCommand::SetBaudRate(_) => 251,
+            PmtkCommand::SetDgpsMode(_) => 301,
+            PmtkCommand::SetSbas(_) => 313,
+            PmtkCommand::SetNmeaOutput(_) | PmtkCommand::ResetNmeaOutput => 314,
+            PmtkCommand::QueryFirmware => 605,
+        }
+    }
+
+    fn encode_parameters<W: Write>(&self, writer: &mut W) -> Result<(), NmeaEncodeError> {
+        match self {
+            PmtkCommand::Standby => write!(writer, ",0")?,
+            PmtkCommand::SetUpdateRate(interval) => {
+                if *interval < 100 || *interval > 10000 {
+                    return Err(NmeaEncodeError::RangeError);
+                }
+                write!(writer, ",{}", interval)?;
+            }
+            PmtkCommand::SetBaudRate(baud_rate) => write!(writer, ",{}", baud_rate.value())?,
+            PmtkCommand::SetDgpsMode(mode) => {
+                let mode = match mode {
+                    PmtkDgpsMode::NoDgps => 0,
+                    PmtkDgpsMode::Rtcm => 1,
+                    PmtkDgpsMode::Sbas => 2,
+                };
+                write!(writer, ",{}", mode)?;
+            }
+            PmtkCommand::SetSbas(enabled) => write!(writer, ",{}", *enabled as u8)?,
+            PmtkCommand::SetNmeaOutput(mask) => {
+                for rate in mask.rates().iter() {
+                    if *rate > 5 {
+                        return Err(NmeaEncodeError::RangeError);
+                    }
+                    write!(writer, ",{}", rate)?;
+                }
+            }
+            PmtkCommand::ResetNmeaOutput => write!(writer, ",-1")?,
+            PmtkCommand::HotStart
+            | PmtkCommand::WarmStart
+            | PmtkCommand::ColdStart
+            | PmtkCommand::FullColdStart
+            | PmtkCommand::QueryFirmware => {}
+        }
+
+        Ok(())
+    }
+}
+
+/// Encodes the given command into a complete PMTK sentence including the
+/// checksum and the terminating \r\n
+pub fn encode_pmtk_command<W: Write>(
+    writer: &mut W,
+    command: &PmtkCommand,
+) -> Result<(), NmeaEncodeError> {
+    writer.write_char('$')?;
+    let mut checksum_writer = ChecksumWriter::new(writer);
+    write!(checksum_writer, "PMTK{:03}", command.id())?;
+    command.encode_parameters(&mut checksum_writer)?;
+    let checksum = checksum_writer.checksum;
+
+    write!(writer, "*{:02X}\r\n", checksum)?;
+    Ok(())
+}
+
+/// Encodes the given command into the buffer, see encode_pmtk_command.
+/// Returns the amount of bytes written on success
+pub fn encode_pmtk_command_to_slice(
+    buffer: &mut [u8],
+    command: &PmtkCommand,
+) -> Result<usize, NmeaEncodeError> {
+    let length = buffer.len();
+    let mut writer = SliceWriter::new(buffer);
+
+    match encode_pmtk_command(&mut writer, command) {
+        Ok(()) => Ok(writer.position),
+        Err(_) if writer.overflow => Err(NmeaEncodeError::BufferLengthError(length)),
+        Err(error) => Err(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::SentenceData;
+    use crate::parse_nmea_sentence;
+    use crate::proprietary::Proprietary;
+
+    fn encode<'b>(command: &PmtkCommand, buffer: &'b mut [u8]) -> &'b [u8] {
+        let length = encode_pmtk_command_to_slice(buffer, command).unwrap();
+        &buffer[..length]
+    }
+
+    #[test]
+    fn encodes_commands() {
+        let commands: [(PmtkCommand, &[u8]); 7] = [
+            (PmtkCommand::HotStart, b"$PMTK101*32\r\n"),
+            (PmtkCommand::Standby, b"$PMTK161,0*28\r\n"),
+            (PmtkCommand::SetUpdateRate(1000), b"$PMTK220,1000*1F\r\n"),
+            (
+                PmtkCommand::SetBaudRate(PmtkBaudRate::B9600),
+                b"$PMTK251,9600*17\r\n",
+            ),
+            (PmtkCommand::SetSbas(true), b"$PMTK313,1*2E\r\n"),
+            (PmtkCommand::ResetNmeaOutput, b"$PMTK314,-1*04\r\n"),
+            (PmtkCommand::QueryFirmware, b"$PMTK605*31\r\n"),
+        ];
+        for (command, expected) in commands.iter() {
+            let mut buffer = [0; 64];
+            assert_eq!(encode(command, &mut buffer), *expected);
+        }
+    }
+
+    #[test]
+    fn encodes_output_rates_in_sentence_order() {
+        let mask = PmtkOutputMask {
+            rmc: 1,
+            gga: 1,
+            ..PmtkOutputMask::default()
+        };
+        let mut buffer = [0; 64];
+        assert_eq!(
+            encode(&PmtkCommand::SetNmeaOutput(mask), &mut buffer),
+            &b"$PMTK314,0,1,0,1,0,0,0,0,0,0,0,0,0,0,0,0,0,0,0*28\r\n"[..]
+        );
+
+        let mask = PmtkOutputMask {
+            gsv: 5,
+            zda: 1,
+            ..mask
+        };
+        assert_eq!(
+            encode(&PmtkCommand::SetNmeaOutput(mask), &mut buffer),
+            &b"$PMTK314,0,1,0,1,0,5,0,0,0,0,0,0,0,0,0,0,0,1,0*2C\r\n"[..]
+        );
+    }
+
+    #[test]
+    fn encoded_commands_verify_as_proprietary_sentences() {
+        let mut buffer = [0; 64];
+        let sentence = encode(&PmtkCommand::SetUpdateRate(200), &mut buffer);
+        match parse_nmea_sentence(sentence) {
+            Ok(SentenceData::Proprietary(Proprietary::Raw(sentence))) => {
+                assert_eq!(sentence.manufacturer(), b"MTK");
+                assert_eq!(sentence.sentence_id(), b"220");
+                assert_eq!(sentence.fields().next(), Some(&b"200"[..]));
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    #[test]
+    fn rejects_parameters_out_of_range() {
+        let mut buffer = [0; 64];
+        assert_eq!(
+            encode(&PmtkCommand::SetUpdateRate(100), &mut buffer),
+            &b"$PMTK220,100*2F\r\n"[..]
+        );
+        assert_eq!(
+            encode(&PmtkCommand::SetUpdateRate(10000), &mut buffer),
+            &b"$PMTK220,10000*2F\r\n"[..]
+        );
+        assert_eq!(
+            encode_pmtk_command_to_slice(&mut buffer, &PmtkCommand::SetUpdateRate(99)),
+            Err(NmeaEncodeError::RangeError)
+        );
+        assert_eq!(
+            encode_pmtk_command_to_slice(&mut buffer, &PmtkCommand::SetUpdateRate(10001)),
+            Err(NmeaEncodeError::RangeError)
+        );
+
+        let mask = PmtkOutputMask {
+            gga: 6,
+            ..PmtkOutputMask::default()
+        };
+        assert_eq!(
+            encode_pmtk_command_to_slice(&mut buffer, &PmtkCommand::SetNmeaOutput(mask)),
+            Err(NmeaEncodeError::RangeError)
+        );
+    }
+
+    #[test]
+    fn reports_buffers_that_are_too_small() {
+        let mut buffer = [0; 12];
+        assert_eq!(
+            encode_pmtk_command_to_slice(&mut buffer, &PmtkCommand::SetUpdateRate(1000)),
+            Err(NmeaEncodeError::BufferLengthError(12))
+        );
+    }
+}
@@ -0,0 +1,306 @@
+use crate::errors::NmeaSentenceError;
+use crate::parse::{parse_result_to_data, PgrmData, PmtkData, PubxData};
+use crate::parsers;
+use crate::sentences::{GeneralSentence, SentenceType};
+
+/// A proprietary sentence whose checksum has been verified but whose data
+/// has not been parsed into a specific data struct yet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProprietarySentence<'a> {
+    raw: GeneralSentence<'a>,
+}
+
+impl<'a> ProprietarySentence<'a> {
+    /// Generates a new ProprietarySentence instance with verified checksum,
+    /// sentences whose address does not start with a P are rejected
+    pub fn new(sentence: &'a [u8]) -> Result<Self, NmeaSentenceError<'a>> {
+        let raw = GeneralSentence::new(sentence)?;
+        if raw.sentence_type != SentenceType::Proprietary {
+            return Err(NmeaSentenceError::GeneralParsingError);
+        }
+
+        Ok(ProprietarySentence { raw })
+    }
+
+    /// The three letter manufacturer code, e.g. `UBX` for `$PUBX`
+    pub fn manufacturer(&self) -> &'a [u8] {
+        self.raw.prefix.get(2..5).unwrap_or_default()
+    }
+
+    /// The rest of the address following the manufacturer code, e.g. `001`
+    /// for `$PMTK001` or `E` for `$PGRME`. It is empty for `$PUBX`.
+    pub fn sentence_id(&self) -> &'a [u8] {
+        let prefix = self.raw.prefix;
+        let address = match prefix.last() {
+            Some(b',') => &prefix[..prefix.len() - 1],
+            _ => prefix,
+        };
+        address.get(5..).unwrap_or_default()
+    }
+
+    /// The checksum transmitted with the sentence
+    pub fn checksum(&self) -> u8 {
+        self.raw.checksum
+    }
+
+    /// Returns an iterator over the raw comma separated data fields
+    /// of the sentence, excluding the address field
+    pub fn fields(&self) -> impl Iterator<Item = &'a [u8]> {
+        self.raw.fields()
+    }
+
+    /// Parses the sentence with the first of the given parsers that is registered
+    /// for its manufacturer and knows the sentence. This is how raw sentences
+    /// returned by parse_nmea_sentence or the decoder are handed to your own parsers.
+    pub fn parse_with<T>(
+        self,
+        parsers: &[&dyn ProprietaryParser<'a, T>],
+    ) -> Result<Proprietary<'a, T>, NmeaSentenceError<'a>> {
+        for parser in parsers {
+            if parser.manufacturer() != self.manufacturer() {
+                continue;
+            }
+
+            if let Some(data) = parser.parse(&self)? {
+                return Ok(Proprietary::Parsed(data));
+            }
+        }
+
+        Ok(Proprietary::Raw(self))
+    }
+
+    /// The data following the address including the terminating `*`,
+    /// this is what the nom parsers work on
+    pub(crate) fn data(&self) -> &'a [u8] {
+        self.raw.data
+    }
+}
+
+/// Parses the proprietary sentences of a single manufacturer into values of type T.
+///
+/// Parsers are looked up by their manufacturer code. A table of them can be
+/// kept in a static, for example
+/// `static PARSERS: &[&dyn for<'a> ProprietaryParser<'a, MyData<'a>>] = &[&UbloxParser, &MyParser];`
+/// and handed to parse_proprietary_sentence without requiring any allocations.
+/// The built-in parsers work for any T that can be created from their data.
+pub trait ProprietaryParser<'a, T>: Sync {
+    /// The three letter manufacturer code of the sentences the parser handles
+    fn manufacturer(&self) -> &[u8];
+
+    /// Parses a sentence of the manufacturer, sentences the parser does not
+    /// know about yield None so they are passed on as raw fields instead
+    fn parse(&self, sentence: &ProprietarySentence<'a>)
+        -> Result<Option<T>, NmeaSentenceError<'a>>;
+}
+
+/// The proprietary sentences the library comes with parsers for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProprietaryData<'a> {
+    PUBX(PubxData<'a>),
+    PMTK(PmtkData<'a>),
+    PGRM(PgrmData<'a>),
+}
+
+impl<'a> From<PubxData<'a>> for ProprietaryData<'a> {
+    fn from(data: PubxData<'a>) -> Self {
+        ProprietaryData::PUBX(data)
+    }
+}
+
+impl<'a> From<PmtkData<'a>> for ProprietaryData<'a> {
+    fn from(data: PmtkData<'a>) -> Self {
+        ProprietaryData::PMTK(data)
+    }
+}
+
+impl<'a> From<PgrmData<'a>> for ProprietaryData<'a> {
+    fn from(data: PgrmData<'a>) -> Self {
+        ProprietaryData::PGRM(data)
+    }
+}
+
+/// A proprietary sentence, either parsed into a typed value or, if no parser
+/// knows about it, its verified raw fields
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Proprietary<'a, T = ProprietaryData<'a>> {
+    Parsed(T),
+    Raw(ProprietarySentence<'a>),
+}
+
+/// Parses the u-blox PUBX,00, PUBX,03 and PUBX,04 messages
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UbloxParser;
+
+impl<'a, T: From<PubxData<'a>>> ProprietaryParser<'a, T> for UbloxParser {
+    fn manufacturer(&self) -> &[u8] {
+        b"UBX"
+    }
+
+    fn parse(
+        &self,
+        sentence: &ProprietarySentence<'a>,
+    ) -> Result<Option<T>, NmeaSentenceError<'a>> {
+        if !sentence.sentence_id().is_empty() {
+            return Ok(None);
+        }
+
+        match sentence.fields().next() {
+            Some(b"00") | Some(b"03") | Some(b"04") => {
+                let data = parse_result_to_data(parsers::pubx::parse_pubx(sentence.data()))?;
+                Ok(Some(data.into()))
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
+/// Parses the MediaTek PMTK001 acknowledgements and PMTK705 firmware releases
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MediatekParser;
+
+impl<'a, T: From<PmtkData<'a>>> ProprietaryParser<'a, T> for MediatekParser {
+    fn manufacturer(&self) -> &[u8] {
+        b"MTK"
+    }
+
+    fn parse(
+        &self,
+        sentence: &ProprietarySentence<'a>,
+    ) -> Result<Option<T>, NmeaSentenceError<'a>> {
+        let data = match sentence.sentence_id() {
+            b"001" => parse_result_to_data(parsers::pmtk::parse_pmtk_ack(sentence.data()))?,
+            b"705" => parse_result_to_data(parsers::pmtk::parse_pmtk_firmware(sentence.data()))?,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(data.into()))
+    }
+}
+
+/// Parses the Garmin PGRME position errors, PGRMZ altitudes and PGRMM map datums
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GarminParser;
+
+impl<'a, T: From<PgrmData<'a>>> ProprietaryParser<'a, T> for GarminParser {
+    fn manufacturer(&self) -> &[u8] {
+        b"GRM"
+    }
+
+    fn parse(
+        &self,
+        sentence: &ProprietarySentence<'a>,
+    ) -> Result<Option<T>, NmeaSentenceError<'a>> {
+        let data = match sentence.sentence_id() {
+            b"E" => parse_result_to_data(parsers::pgrm::parse_pgrme(sentence.data()))?,
+            b"Z" => parse_result_to_data(parsers::pgrm::parse_pgrmz(sentence.data()))?,
+            b"M" => parse_result_to_data(parsers::pgrm::parse_pgrmm(sentence.data()))?,
+            _ => return Ok(None),
+        };
+
+        Ok(Some(data.into()))
+    }
+}
+
+/// The parsers parse_nmea_sentence and the decoder use for proprietary sentences,
+/// sentences none of them knows are returned raw
+pub static BUILTIN_PROPRIETARY_PARSERS: &[&dyn for<'a> ProprietaryParser<
+    'a,
+    ProprietaryData<'a>,
+>] = &[&UbloxParser, &MediatekParser, &GarminParser];
+
+/// Verifies a proprietary sentence and parses it with the first of the given
+/// parsers that is registered for its manufacturer and knows the sentence.
+/// Sentences no parser knows about are returned as raw fields.
+pub fn parse_proprietary_sentence<'a, T>(
+    sentence: &'a [u8],
+    parsers: &[&dyn ProprietaryParser<'a, T>],
+) -> Result<Proprietary<'a, T>, NmeaSentenceError<'a>> {
+    ProprietarySentence::new(sentence)?.parse_with(parsers)
+}
+
+pub(crate) fn parse_builtin(
+    general_sentence: GeneralSentence<'_>,
+) -> Result<Proprietary<'_>, NmeaSentenceError<'_>> {
+    ProprietarySentence {
+        raw: general_sentence,
+    }
+    .parse_with(BUILTIN_PROPRIETARY_PARSERS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::NmeaSentenceError;
+
+    #[test]
+    fn splits_address_into_manufacturer_and_sentence_id() {
+        let sentence = ProprietarySentence::new(b"$PMTK001,604,3*32\r\n").unwrap();
+        assert_eq!(sentence.manufacturer(), b"MTK");
+        assert_eq!(sentence.sentence_id(), b"001");
+
+        let sentence = ProprietarySentence::new(b"$PMTK605*31").unwrap();
+        assert_eq!(sentence.manufacturer(), b"MTK");
+        assert_eq!(sentence.sentence_id(), b"605");
+
+        let sentence = ProprietarySentence::new(b"$PUBX,41,1,0007,0003,19200,0*25").unwrap();
+        assert_eq!(sentence.manufacturer(), b"UBX");
+        assert_eq!(sentence.sentence_id(), b"");
+    }
+
+    #[test]
+    fn rejects_incomplete_manufacturer_code() {
+        assert_eq!(
+            ProprietarySentence::new(b"$PAB,12345*4E"),
+            Err(NmeaSentenceError::GeneralParsingError)
+        );
+        assert_eq!(
+            ProprietarySentence::new(b"$PA,B,12345*62"),
+            Err(NmeaSentenceError::GeneralParsingError)
+        );
+    }
+
+    #[test]
+    fn unknown_sentences_are_returned_raw() {
+        match crate::parse_nmea_sentence(b"$PSRF100,1,9600,8,1,0*0D") {
+            Ok(crate::parse::SentenceData::Proprietary(Proprietary::Raw(sentence))) => {
+                assert_eq!(sentence.manufacturer(), b"SRF");
+                assert_eq!(sentence.sentence_id(), b"100");
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+    }
+
+    /// Returns the sentence id of any SiRF sentence
+    struct SirfParser;
+
+    impl<'a> ProprietaryParser<'a, &'a [u8]> for SirfParser {
+        fn manufacturer(&self) -> &[u8] {
+            b"SRF"
+        }
+
+        fn parse(
+            &self,
+            sentence: &ProprietarySentence<'a>,
+        ) -> Result<Option<&'a [u8]>, NmeaSentenceError<'a>> {
+            Ok(Some(sentence.sentence_id()))
+        }
+    }
+
+    #[test]
+    fn raw_sentences_can_be_parsed_with_other_parsers() {
+        let sentence = match crate::parse_nmea_sentence(b"$PSRF100,1,9600,8,1,0*0D") {
+            Ok(crate::parse::SentenceData::Proprietary(Proprietary::Raw(sentence))) => sentence,
+            result => panic!("unexpected result {:?}", result),
+        };
+
+        let parsers: [&dyn ProprietaryParser<'_, &[u8]>; 1] = [&SirfParser];
+        assert_eq!(
+            sentence.parse_with(&parsers),
+            Ok(Proprietary::Parsed(&b"100"[..]))
+        );
+        assert_eq!(
+            sentence.parse_with(&parsers[..0]),
+            Ok(Proprietary::Raw(sentence))
+        );
+    }
+}
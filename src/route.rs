@@ -0,0 +1,271 @@
+use crate::assembly::{sentence_numbers, SequenceTracker};
+use crate::errors::AssemblyError;
+use crate::parse::{iter_waypoints, RteData, RteMode};
+use crate::sentences::Talker;
+use core::fmt;
+
+/// The maximum amount of waypoints a single Route can hold
+pub const MAX_ROUTE_WAYPOINTS: usize = 64;
+
+/// The maximum length of a waypoint or route identifier inside of a Route
+pub const MAX_WAYPOINT_ID_LENGTH: usize = 16;
+
+/// The maximum amount of routes a RouteAssembler can assemble at the same time
+pub const MAX_ROUTES_IN_PROGRESS: usize = 4;
+
+/// A waypoint or route identifier copied out of its sentence
+#[derive(Clone, Copy, PartialEq)]
+pub struct WaypointId {
+    bytes: [u8; MAX_WAYPOINT_ID_LENGTH],
+    length: usize,
+}
+
+impl WaypointId {
+    fn new(id: &[u8]) -> Result<Self, AssemblyError> {
+        if id.len() > MAX_WAYPOINT_ID_LENGTH {
+            return Err(AssemblyError::CapacityError);
+        }
+
+        let mut bytes = [0; MAX_WAYPOINT_ID_LENGTH];
+        bytes[..id.len()].copy_from_slice(id);
+        Ok(WaypointId {
+            bytes,
+            length: id.len(),
+        })
+    }
+
+    /// Returns the identifier as it was sent
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.length]
+    }
+}
+
+impl fmt::Debug for WaypointId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match core::str::from_utf8(self.as_bytes()) {
+            Ok(id) => id.fmt(f),
+            Err(_) => self.as_bytes().fmt(f),
+        }
+    }
+}
+
+/// A route of a single talker, assembled from all of its RTE sentences
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Route {
+    pub talker: Talker,
+    pub mode: Option<RteMode>,
+    route_id: Option<WaypointId>,
+    waypoints: [Option<WaypointId>; MAX_ROUTE_WAYPOINTS],
+    length: usize,
+}
+
+impl Route {
+    fn new(talker: Talker, mode: Option<RteMode>, route_id: Option<WaypointId>) -> Self {
+        Route {
+            talker,
+            mode,
+            route_id,
+            waypoints: [None; MAX_ROUTE_WAYPOINTS],
+            length: 0,
+        }
+    }
+
+    /// Returns the identifier of the route if it was sent
+    pub fn route_id(&self) -> Option<&[u8]> {
+        self.route_id.as_ref().map(WaypointId::as_bytes)
+    }
+
+    /// Returns all waypoints of the route in the order they were listed, for a
+    /// working route the first one is the waypoint the vessel is coming from.
+    /// Waypoints whose identifier was left empty are None.
+    pub fn waypoints(&self) -> &[Option<WaypointId>] {
+        &self.waypoints[..self.length]
+    }
+
+    fn push(&mut self, waypoint: Option<&[u8]>) -> Result<(), AssemblyError> {
+        if self.length == MAX_ROUTE_WAYPOINTS {
+            return Err(AssemblyError::CapacityError);
+        }
+
+        self.waypoints[self.length] = match waypoint {
+            Some(waypoint) => Some(WaypointId::new(waypoint)?),
+            None => None,
+        };
+        self.length += 1;
+        Ok(())
+    }
+}
+
+/// Collects the RTE sentences of a route until it is complete and turns them
+/// into a Route. Routes are told apart by their talker and route identifier.
+/// Works on fixed capacity storage so it does not require any allocations.
+#[derive(Debug, Clone)]
+pub struct RouteAssembler {
+    routes: SequenceTracker<(Talker, Option<WaypointId>), Route, MAX_ROUTES_IN_PROGRESS>,
+}
+
+impl Default for RouteAssembler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RouteAssembler {
+    /// Generates a new assembler without any routes in progress
+    pub fn new() -> Self {
+        RouteAssembler {
+            routes: SequenceTracker::new(),
+        }
+    }
+
+    /// Discards all routes that are in progress
+    pub fn reset(&mut self) {
+        self.routes.reset();
+    }
+
+    /// Adds an RTE sentence of the given talker to its route, returns the Route
+    /// once the last sentence of it has been added.
+    ///
+    /// If a sentence does not continue the route in progress the route is discarded
+    /// and a SentenceOrderError is returned. A first sentence always starts a new
+    /// route though, so it is kept even if it caused the error.
+    pub fn push(&mut self, talker: Talker, data: &RteData) -> Result<Option<Route>, AssemblyError> {
+        let (number_of_sentences, sentence_num) =
+            sentence_numbers(data.number_of_sentences, data.sentence_num)?;
+        let route_id = match data.route_id {
+            Some(route_id) => Some(WaypointId::new(route_id)?),
+            None => None,
+        };
+
+        self.routes.push(
+            (talker, route_id),
+            number_of_sentences,
+            sentence_num,
+            || Route::new(talker, data.mode, route_id),
+            |route| {
+                for waypoint in iter_waypoints(&data.waypoints) {
+                    route.push(waypoint)?;
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parsers::rte::parse_rte;
+
+    fn rte(data: &'static [u8]) -> RteData<'static> {
+        parse_rte(data).unwrap().1
+    }
+
+    fn ids(route: &Route) -> [Option<&[u8]>; 8] {
+        let mut ids = [None; 8];
+        for (id, waypoint) in ids.iter_mut().zip(route.waypoints()) {
+            *id = waypoint.as_ref().map(WaypointId::as_bytes);
+        }
+        ids
+    }
+
+    #[test]
+    fn assembles_a_complete_route() {
+        let mut assembler = RouteAssembler::new();
+        assert_eq!(assembler.push(Talker::GP, &rte(b"2,1,c,R1,A,B*")), Ok(None));
+
+        let route = assembler
+            .push(Talker::GP, &rte(b"2,2,c,R1,,D*"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(route.talker, Talker::GP);
+        assert_eq!(route.mode, Some(RteMode::CompleteRoute));
+        assert_eq!(route.route_id(), Some(&b"R1"[..]));
+        assert_eq!(route.waypoints().len(), 4);
+        assert_eq!(
+            ids(&route)[..4],
+            [Some(&b"A"[..]), Some(&b"B"[..]), None, Some(&b"D"[..])]
+        );
+    }
+
+    #[test]
+    fn assembles_interleaved_routes() {
+        let mut assembler = RouteAssembler::new();
+        assert_eq!(assembler.push(Talker::GP, &rte(b"2,1,c,R1,A*")), Ok(None));
+        assert_eq!(assembler.push(Talker::GP, &rte(b"2,1,c,R2,X*")), Ok(None));
+        assert_eq!(assembler.push(Talker::GN, &rte(b"2,1,c,R1,M*")), Ok(None));
+
+        let second = assembler
+            .push(Talker::GP, &rte(b"2,2,c,R2,Y*"))
+            .unwrap()
+            .unwrap();
+        let first = assembler
+            .push(Talker::GP, &rte(b"2,2,c,R1,B*"))
+            .unwrap()
+            .unwrap();
+        let other_talker = assembler
+            .push(Talker::GN, &rte(b"2,2,c,R1,N*"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(ids(&first)[..2], [Some(&b"A"[..]), Some(&b"B"[..])]);
+        assert_eq!(ids(&second)[..2], [Some(&b"X"[..]), Some(&b"Y"[..])]);
+        assert_eq!(ids(&other_talker)[..2], [Some(&b"M"[..]), Some(&b"N"[..])]);
+    }
+
+    #[test]
+    fn reports_capacity_overflow() {
+        let mut assembler = RouteAssembler::new();
+        let sentences: [&'static [u8]; MAX_ROUTES_IN_PROGRESS] = [
+            b"2,1,c,R1,A*",
+            b"2,1,c,R2,A*",
+            b"2,1,c,R3,A*",
+            b"2,1,c,R4,A*",
+        ];
+        for sentence in sentences.iter() {
+            assert_eq!(assembler.push(Talker::GP, &rte(sentence)), Ok(None));
+        }
+        assert_eq!(
+            assembler.push(Talker::GP, &rte(b"2,1,c,R5,A*")),
+            Err(AssemblyError::CapacityError)
+        );
+
+        assembler.reset();
+        assert_eq!(
+            assembler.push(Talker::GP, &rte(b"1,1,c,R1,WAYPOINT-NAME-TOO-LONG*")),
+            Err(AssemblyError::CapacityError)
+        );
+
+        // Four sentences with 20 waypoints each exceed the capacity of a route
+        let twenty: &'static [u8] = b"4,1,c,R1,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A*";
+        assert_eq!(assembler.push(Talker::GP, &rte(twenty)), Ok(None));
+        assert_eq!(
+            assembler.push(
+                Talker::GP,
+                &rte(b"4,2,c,R1,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A*")
+            ),
+            Ok(None)
+        );
+        assert_eq!(
+            assembler.push(
+                Talker::GP,
+                &rte(b"4,3,c,R1,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A*")
+            ),
+            Ok(None)
+        );
+        assert_eq!(
+            assembler.push(
+                Talker::GP,
+                &rte(b"4,4,c,R1,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A,A*")
+            ),
+            Err(AssemblyError::CapacityError)
+        );
+        // The overflowing route was discarded
+        assert_eq!(
+            assembler.push(Talker::GP, &rte(b"4,4,c,R1,A*")),
+            Err(AssemblyError::SentenceOrderError {
+                expected: 1,
+                received: 4
+            })
+        );
+    }
+}
@@ -2,18 +2,50 @@ use crate::errors::NmeaSentenceError;
 
 macro_rules! general_sentences {
     ($($string_type:tt => $STYPE:ident),+) => {
-        pub (crate) fn parse_general_sentence(sentence: &[u8]) -> Result<GeneralSentence, NmeaSentenceError> {
-            let (prefix, rest) = sentence.split_at(7);
-            let (data, checksum) = rest.split_at(rest.len() - 4);
-            let checksum = parse_hex(&checksum[0..2])?;
+        pub (crate) fn parse_general_sentence(sentence: &[u8]) -> Result<GeneralSentence<'_>, NmeaSentenceError<'_>> {
+            // The shortest sentence possible looks like $TTSSS,*XX
+            if sentence.len() < 10 {
+                return Err(NmeaSentenceError::GeneralParsingError);
+            }
 
+            let (rest, checksum) = sentence.split_at(sentence.len() - 2);
+            if rest.last() != Some(&b'*') {
+                return Err(NmeaSentenceError::GeneralParsingError);
+            }
+            let checksum = parse_hex(checksum)?;
+
+            // The address of a proprietary sentence is made up of a P, the three letter
+            // manufacturer code and an optional sentence id of arbitrary length, e.g. $PMTK001
+            if rest[1] == b'P' {
+                let (address_end, end) = match rest.iter().position(|byte| *byte == b',') {
+                    Some(comma) => (comma, comma + 1),
+                    None => (rest.len() - 1, rest.len() - 1),
+                };
+                if address_end < 5
+                    || !rest[2..address_end].iter().all(|byte| byte.is_ascii_alphanumeric())
+                {
+                    return Err(NmeaSentenceError::GeneralParsingError);
+                }
+
+                let (prefix, data) = rest.split_at(end);
+                return Ok(GeneralSentence {
+                    talker: Talker::Proprietary,
+                    sentence_type: SentenceType::Proprietary,
+                    data,
+                    checksum,
+                    prefix,
+                });
+            }
+
+            let (prefix, data) = rest.split_at(7);
              match &prefix[3..6] {
                  $(
                     $string_type => Ok(GeneralSentence {
+                                            talker: Talker::from_bytes(&prefix[1..3]),
                                             sentence_type: SentenceType::$STYPE,
-                                            data: data,
-                                            checksum: checksum,
-                                            prefix: prefix
+                                            data,
+                                            checksum,
+                                            prefix
                                         }
                                      ),
                  )+
@@ -28,10 +60,95 @@ macro_rules! general_sentences {
             $(
                 $STYPE,
             )+
+            /// Proprietary sentence, the address starts with a P followed
+            /// by the manufacturer code
+            Proprietary,
+        }
+
+        impl SentenceType {
+            /// Returns the three letter identifier of the sentence type
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $(
+                        SentenceType::$STYPE => stringify!($STYPE),
+                    )+
+                    SentenceType::Proprietary => "P",
+                }
+            }
         }
     }
 }
 
+macro_rules! talkers {
+    ($($string_talker:tt => $TALKER:ident : $doc:expr),+) => {
+        /// Enum of the talkers a sentence can originate from
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub enum Talker {
+            $(
+                #[doc = $doc]
+                $TALKER,
+            )+
+            /// Proprietary sentence, the talker id starts with a P
+            Proprietary,
+            /// A talker id the library does not know about
+            Unknown([u8; 2]),
+        }
+
+        impl Talker {
+            /// Translates the two byte talker id of a sentence into a Talker
+            pub fn from_bytes(id: &[u8]) -> Self {
+                match id {
+                    $(
+                        $string_talker => Talker::$TALKER,
+                    )+
+                    [b'P', _] => Talker::Proprietary,
+                    [first, second] => Talker::Unknown([*first, *second]),
+                    _ => Talker::Unknown([0, 0]),
+                }
+            }
+
+            /// Returns the two letter id of the talker, proprietary talkers
+            /// and ids that are not valid ASCII have none
+            pub fn as_str(&self) -> Option<&str> {
+                match self {
+                    $(
+                        Talker::$TALKER => Some(stringify!($TALKER)),
+                    )+
+                    Talker::Proprietary => None,
+                    Talker::Unknown(id) => core::str::from_utf8(id).ok(),
+                }
+            }
+        }
+    }
+}
+
+talkers!(
+    b"AG" => AG: "Autopilot, general",
+    b"AI" => AI: "Automatic Identification System",
+    b"AP" => AP: "Autopilot, magnetic",
+    b"BD" => BD: "BeiDou, legacy talker id",
+    b"EC" => EC: "Electronic Chart Display & Information System",
+    b"GA" => GA: "Galileo",
+    b"GB" => GB: "BeiDou",
+    b"GI" => GI: "NavIC",
+    b"GL" => GL: "GLONASS",
+    b"GN" => GN: "Combined GNSS solution",
+    b"GP" => GP: "GPS",
+    b"GQ" => GQ: "QZSS",
+    b"HC" => HC: "Heading, magnetic compass",
+    b"HE" => HE: "Heading, north seeking gyro",
+    b"HN" => HN: "Heading, non north seeking gyro",
+    b"II" => II: "Integrated instrumentation",
+    b"IN" => IN: "Integrated navigation",
+    b"LC" => LC: "Loran-C",
+    b"RA" => RA: "Radar",
+    b"SD" => SD: "Depth sounder",
+    b"SN" => SN: "Electronic positioning system",
+    b"VW" => VW: "Speed log, mechanical",
+    b"WI" => WI: "Weather instruments",
+    b"YX" => YX: "Transducer"
+);
+
 general_sentences!(
     b"AAM" => AAM,
     b"ABK" => ABK,
@@ -67,8 +184,10 @@ general_sentences!(
     b"HDT" => HDT,
     b"HSC" => HSC,
     b"LCD" => LCD,
+    b"MDA" => MDA,
     b"MSK" => MSK,
     b"MTW" => MTW,
+    b"MWD" => MWD,
     b"MWV" => MWV,
     b"OLN" => OLN,
     b"OSD" => OSD,
@@ -86,6 +205,8 @@ general_sentences!(
     b"TLL" => TLL,
     b"TTM" => TTM,
     b"VBW" => VBW,
+    b"VDM" => VDM,
+    b"VDO" => VDO,
     b"VDR" => VDR,
     b"VHW" => VHW,
     b"VLW" => VLW,
@@ -103,13 +224,57 @@ general_sentences!(
     b"ZTG" => ZTG
 );
 
-fn parse_hex(data: &[u8]) -> Result<u8, NmeaSentenceError> {
-    u8::from_str_radix(unsafe { core::str::from_utf8_unchecked(data) }, 16)
-        .map_err(|_| NmeaSentenceError::HexParsingError(data[0], data[1]))
+impl SentenceType {
+    /// Returns the character a sentence of this type starts with, encapsulated
+    /// sentences such as AIS messages start with a `!` instead of a `$`
+    pub fn start_delimiter(&self) -> char {
+        match self {
+            SentenceType::VDM | SentenceType::VDO => '!',
+            _ => '$',
+        }
+    }
+}
+
+/// The maximum length of a sentence from its start delimiter up to and including
+/// the checksum, the line ending does not count towards it
+pub const MAX_SENTENCE_LENGTH: usize = 102;
+
+/// The maximum length of a proprietary sentence, counted the same way as
+/// MAX_SENTENCE_LENGTH. They are not bound to the length limit of NMEA 0183
+/// and e.g. PUBX,03 easily exceeds it.
+pub const MAX_PROPRIETARY_SENTENCE_LENGTH: usize = 1024;
+
+/// Strips the line ending as well as any other trailing whitespace,
+/// a sentence might be terminated by \r\n, just \n or nothing at all
+fn trim_line_ending(sentence: &[u8]) -> &[u8] {
+    let mut end = sentence.len();
+    while end > 0 && sentence[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+
+    &sentence[..end]
 }
 
-#[derive(Debug, Clone, Copy)]
-pub(crate) struct GeneralSentence<'a> {
+/// Adds the bytes to the checksum of the sentence they belong to, the checksum
+/// covers everything between the start delimiter and the `*`
+pub(crate) fn update_checksum(checksum: u8, bytes: &[u8]) -> u8 {
+    bytes
+        .iter()
+        .fold(checksum, |checksum, byte| checksum ^ byte)
+}
+
+fn parse_hex(data: &[u8]) -> Result<u8, NmeaSentenceError<'_>> {
+    core::str::from_utf8(data)
+        .ok()
+        .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+        .ok_or(NmeaSentenceError::HexParsingError(data[0], data[1]))
+}
+
+/// A sentence whose checksum has been verified but whose data
+/// has not been parsed into a specific data struct yet
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeneralSentence<'a> {
+    pub(crate) talker: Talker,
     pub(crate) sentence_type: SentenceType,
     pub(crate) data: &'a [u8],
     pub(crate) checksum: u8,
@@ -118,8 +283,14 @@ pub(crate) struct GeneralSentence<'a> {
 
 impl<'a> GeneralSentence<'a> {
     /// Generates a new GeneralSentence instance with verified checksum
-    pub(crate) fn new(sentence: &'a [u8]) -> Result<Self, NmeaSentenceError> {
-        if sentence.len() > 102 {
+    pub fn new(sentence: &'a [u8]) -> Result<Self, NmeaSentenceError<'a>> {
+        let sentence = trim_line_ending(sentence);
+        let max_length = if sentence.get(1) == Some(&b'P') {
+            MAX_PROPRIETARY_SENTENCE_LENGTH
+        } else {
+            MAX_SENTENCE_LENGTH
+        };
+        if sentence.len() > max_length {
             return Err(NmeaSentenceError::SentenceLengthError(sentence.len()));
         }
 
@@ -136,17 +307,101 @@ impl<'a> GeneralSentence<'a> {
         Ok(parsed_sentence)
     }
 
+    /// The talker the sentence originates from
+    pub fn talker(&self) -> Talker {
+        self.talker
+    }
+
+    /// The type of the sentence
+    pub fn sentence_type(&self) -> SentenceType {
+        self.sentence_type
+    }
+
+    /// The checksum transmitted with the sentence
+    pub fn checksum(&self) -> u8 {
+        self.checksum
+    }
+
+    /// Returns an iterator over the raw comma separated data fields
+    /// of the sentence, excluding the address field
+    pub fn fields(&self) -> impl Iterator<Item = &'a [u8]> {
+        self.data[..self.data.len() - 1].split(|byte| *byte == b',')
+    }
+
     /// Calculates the actual checksum of a sentence
     fn calc_checksum(&self) -> u8 {
-        let mut checksum = 0;
-        for byte in &self.prefix[1..] {
-            checksum = checksum ^ byte;
-        }
+        let checksum = update_checksum(0, &self.prefix[1..]);
+        update_checksum(checksum, &self.data[..self.data.len() - 1])
+    }
+}
 
-        for byte in &self.data[..self.data.len() - 1] {
-            checksum = checksum ^ byte;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::SentenceData;
+    use crate::parse_nmea_sentence;
+
+    /// Exactly as long as a non proprietary sentence may be, without the line ending
+    const LONGEST: &[u8] = b"$GPGGA,092750.000,5321.6802000000000000000,N,00630.33720000000000000000,W,1,08,1.03,61.7,M,55.2,M,,*76";
+    /// One byte longer than LONGEST
+    const TOO_LONG: &[u8] = b"$GPGGA,092750.000,5321.68020000000000000000,N,00630.33720000000000000000,W,1,08,1.03,61.7,M,55.2,M,,*46";
+    const ROT: &[u8] = b"$HEROT,-11.3,A*35";
+
+    #[test]
+    fn rejects_short_input() {
+        let inputs: [&[u8]; 6] = [b"", b"$", b"\r\n", b"$G", b"$GP", b"*35"];
+        for input in inputs.iter() {
+            assert_eq!(
+                parse_nmea_sentence(input),
+                Err(NmeaSentenceError::GeneralParsingError)
+            );
         }
+    }
+
+    #[test]
+    fn accepts_any_line_ending() {
+        let expected = parse_nmea_sentence(ROT).unwrap();
+        assert!(matches!(expected, SentenceData::ROT(_)));
+        assert_eq!(
+            parse_nmea_sentence(b"$HEROT,-11.3,A*35\n"),
+            Ok(expected.clone())
+        );
+        assert_eq!(parse_nmea_sentence(b"$HEROT,-11.3,A*35\r\n"), Ok(expected));
+    }
+
+    #[test]
+    fn ignores_the_line_ending_in_the_length_limit() {
+        assert_eq!(LONGEST.len(), MAX_SENTENCE_LENGTH);
+        let mut buffer = [b' '; MAX_SENTENCE_LENGTH + 4];
+        buffer[..MAX_SENTENCE_LENGTH].copy_from_slice(LONGEST);
+        buffer[MAX_SENTENCE_LENGTH..MAX_SENTENCE_LENGTH + 2].copy_from_slice(b"\r\n");
+
+        let expected = parse_nmea_sentence(LONGEST).unwrap();
+        assert!(matches!(expected, SentenceData::GGA(_)));
+        assert_eq!(
+            parse_nmea_sentence(&buffer[..MAX_SENTENCE_LENGTH + 2]),
+            Ok(expected.clone())
+        );
+        assert_eq!(parse_nmea_sentence(&buffer), Ok(expected));
+    }
+
+    #[test]
+    fn rejects_sentences_exceeding_the_length_limit() {
+        assert_eq!(
+            parse_nmea_sentence(TOO_LONG),
+            Err(NmeaSentenceError::SentenceLengthError(
+                MAX_SENTENCE_LENGTH + 1
+            ))
+        );
 
-        checksum
+        let mut buffer = [0; MAX_SENTENCE_LENGTH + 3];
+        buffer[..MAX_SENTENCE_LENGTH + 1].copy_from_slice(TOO_LONG);
+        buffer[MAX_SENTENCE_LENGTH + 1..].copy_from_slice(b"\r\n");
+        assert_eq!(
+            parse_nmea_sentence(&buffer),
+            Err(NmeaSentenceError::SentenceLengthError(
+                MAX_SENTENCE_LENGTH + 1
+            ))
+        );
     }
 }
@@ -0,0 +1,281 @@
+use crate::assembly::{sentence_numbers, SequenceTracker};
+use crate::errors::AssemblyError;
+use crate::parse::{GsvData, GsvSatellite};
+use crate::sentences::Talker;
+
+/// The maximum amount of satellites a single SkyView can hold,
+/// this equals nine GSV sentences with four satellites each
+pub const MAX_SKY_VIEW_SATELLITES: usize = 36;
+
+/// The maximum amount of talkers a GsvAssembler can assemble
+/// GSV cycles for at the same time
+pub const MAX_GSV_TALKERS: usize = 6;
+
+const MAX_GSV_SENTENCES: u16 = (MAX_SKY_VIEW_SATELLITES / 4) as u16;
+
+/// The satellite system a satellite belongs to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Constellation {
+    Gps,
+    Sbas,
+    Glonass,
+    Galileo,
+    BeiDou,
+    Qzss,
+    NavIC,
+    Unknown,
+}
+
+impl Constellation {
+    /// Determines the constellation of a satellite based on the talker that
+    /// reported it and, for GPS and combined talkers, its NMEA satellite id
+    pub fn from_talker(talker: Talker, sat_id: u8) -> Self {
+        match (talker, sat_id) {
+            (Talker::GL, _) => Constellation::Glonass,
+            (Talker::GA, _) => Constellation::Galileo,
+            (Talker::GB, _) | (Talker::BD, _) => Constellation::BeiDou,
+            (Talker::GQ, _) => Constellation::Qzss,
+            (Talker::GI, _) => Constellation::NavIC,
+            (_, 1..=32) => Constellation::Gps,
+            (_, 33..=64) => Constellation::Sbas,
+            (Talker::GP, _) => Constellation::Gps,
+            (_, 65..=96) => Constellation::Glonass,
+            (_, 193..=202) => Constellation::Qzss,
+            _ => Constellation::Unknown,
+        }
+    }
+}
+
+/// A satellite inside of a SkyView
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SkySatellite {
+    pub sat_id: u8,
+    pub elevation: Option<f32>,
+    pub azimuth: Option<f32>,
+    pub snr: Option<u8>,
+    pub constellation: Constellation,
+}
+
+const EMPTY_SATELLITE: SkySatellite = SkySatellite {
+    sat_id: 0,
+    elevation: None,
+    azimuth: None,
+    snr: None,
+    constellation: Constellation::Unknown,
+};
+
+/// All satellites in view of a single talker, assembled from a full GSV cycle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SkyView {
+    pub talker: Talker,
+    pub sats_in_view: Option<u8>,
+    satellites: [SkySatellite; MAX_SKY_VIEW_SATELLITES],
+    length: usize,
+}
+
+impl SkyView {
+    fn new(talker: Talker, sats_in_view: Option<u8>) -> Self {
+        SkyView {
+            talker,
+            sats_in_view,
+            satellites: [EMPTY_SATELLITE; MAX_SKY_VIEW_SATELLITES],
+            length: 0,
+        }
+    }
+
+    /// Returns all satellites that were listed throughout the GSV cycle,
+    /// empty satellite fields are left out
+    pub fn satellites(&self) -> &[SkySatellite] {
+        &self.satellites[..self.length]
+    }
+
+    fn push(&mut self, satellite: &GsvSatellite) -> Result<(), AssemblyError> {
+        let sat_id = match satellite.sat_id {
+            Some(sat_id) => sat_id,
+            None => return Ok(()),
+        };
+        if self.length == MAX_SKY_VIEW_SATELLITES {
+            return Err(AssemblyError::CapacityError);
+        }
+
+        self.satellites[self.length] = SkySatellite {
+            sat_id,
+            elevation: satellite.elevation,
+            azimuth: satellite.true_azimuth,
+            snr: satellite.snr,
+            constellation: Constellation::from_talker(self.talker, sat_id),
+        };
+        self.length += 1;
+        Ok(())
+    }
+}
+
+/// Collects the GSV sentences of a talker until their cycle is complete and
+/// turns them into a SkyView. Works on fixed capacity storage so it does not
+/// require any allocations.
+#[derive(Debug, Clone)]
+pub struct GsvAssembler {
+    cycles: SequenceTracker<Talker, SkyView, MAX_GSV_TALKERS>,
+}
+
+impl Default for GsvAssembler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GsvAssembler {
+    /// Generates a new assembler without any cycles in progress
+    pub fn new() -> Self {
+        GsvAssembler {
+            cycles: SequenceTracker::new(),
+        }
+    }
+
+    /// Discards all cycles that are in progress
+    pub fn reset(&mut self) {
+        self.cycles.reset();
+    }
+
+    /// Adds a GSV sentence of the given talker to its cycle, returns the SkyView
+    /// once the last sentence of the cycle has been added.
+    ///
+    /// If a sentence does not continue the cycle in progress the cycle is discarded
+    /// and a SentenceOrderError is returned. A first sentence always starts a new
+    /// cycle though, so it is kept even if it caused the error.
+    pub fn push(
+        &mut self,
+        talker: Talker,
+        data: &GsvData,
+    ) -> Result<Option<SkyView>, AssemblyError> {
+        let (number_of_sentences, sentence_num) =
+            sentence_numbers(data.number_of_sentences, data.sentence_num)?;
+        if number_of_sentences > MAX_GSV_SENTENCES {
+            return Err(AssemblyError::CapacityError);
+        }
+
+        self.cycles.push(
+            talker,
+            number_of_sentences,
+            sentence_num,
+            || SkyView::new(talker, data.sats_in_view),
+            |view| {
+                for satellite in data.sats_info.iter().flatten() {
+                    view.push(satellite)?;
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn gsv(number_of_sentences: u16, sentence_num: u16, sat_ids: &[u8]) -> GsvData {
+        let mut sats_info = [None; 4];
+        for (info, sat_id) in sats_info.iter_mut().zip(sat_ids) {
+            *info = Some(GsvSatellite {
+                sat_id: Some(*sat_id),
+                elevation: Some(45.0),
+                true_azimuth: Some(180.0),
+                snr: Some(40),
+            });
+        }
+
+        GsvData {
+            number_of_sentences: Some(number_of_sentences),
+            sentence_num: Some(sentence_num),
+            sats_in_view: Some(number_of_sentences as u8 * 4),
+            sats_info,
+        }
+    }
+
+    fn sat_ids(view: &SkyView) -> [u8; MAX_SKY_VIEW_SATELLITES] {
+        let mut ids = [0; MAX_SKY_VIEW_SATELLITES];
+        for (id, satellite) in ids.iter_mut().zip(view.satellites()) {
+            *id = satellite.sat_id;
+        }
+        ids
+    }
+
+    #[test]
+    fn assembles_a_complete_cycle() {
+        let mut assembler = GsvAssembler::new();
+        assert_eq!(
+            assembler.push(Talker::GP, &gsv(2, 1, &[1, 2, 3, 4])),
+            Ok(None)
+        );
+
+        let view = assembler
+            .push(Talker::GP, &gsv(2, 2, &[5, 40]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(view.talker, Talker::GP);
+        assert_eq!(view.satellites().len(), 6);
+        assert_eq!(sat_ids(&view)[..6], [1, 2, 3, 4, 5, 40]);
+        assert_eq!(view.satellites()[5].constellation, Constellation::Sbas);
+    }
+
+    #[test]
+    fn assembles_interleaved_talkers() {
+        let mut assembler = GsvAssembler::new();
+        assert_eq!(
+            assembler.push(Talker::GP, &gsv(2, 1, &[1, 2, 3, 4])),
+            Ok(None)
+        );
+        assert_eq!(
+            assembler.push(Talker::GL, &gsv(2, 1, &[65, 66, 67, 68])),
+            Ok(None)
+        );
+
+        let gps = assembler
+            .push(Talker::GP, &gsv(2, 2, &[5]))
+            .unwrap()
+            .unwrap();
+        let glonass = assembler
+            .push(Talker::GL, &gsv(2, 2, &[69]))
+            .unwrap()
+            .unwrap();
+        assert_eq!(sat_ids(&gps)[..5], [1, 2, 3, 4, 5]);
+        assert_eq!(sat_ids(&glonass)[..5], [65, 66, 67, 68, 69]);
+        assert!(glonass
+            .satellites()
+            .iter()
+            .all(|satellite| satellite.constellation == Constellation::Glonass));
+    }
+
+    #[test]
+    fn reports_capacity_overflow() {
+        let mut assembler = GsvAssembler::new();
+        assert_eq!(
+            assembler.push(Talker::GP, &gsv(MAX_GSV_SENTENCES + 1, 1, &[1])),
+            Err(AssemblyError::CapacityError)
+        );
+
+        let talkers = [
+            Talker::GP,
+            Talker::GL,
+            Talker::GA,
+            Talker::GB,
+            Talker::GQ,
+            Talker::GI,
+        ];
+        assert_eq!(talkers.len(), MAX_GSV_TALKERS);
+        for talker in talkers.iter() {
+            assert_eq!(assembler.push(*talker, &gsv(2, 1, &[1])), Ok(None));
+        }
+        assert_eq!(
+            assembler.push(Talker::GN, &gsv(2, 1, &[1])),
+            Err(AssemblyError::CapacityError)
+        );
+
+        // Completing a cycle frees its slot again
+        assert!(assembler
+            .push(Talker::GP, &gsv(2, 2, &[2]))
+            .unwrap()
+            .is_some());
+        assert_eq!(assembler.push(Talker::GN, &gsv(2, 1, &[1])), Ok(None));
+    }
+}